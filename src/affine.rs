@@ -0,0 +1,109 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Mul,
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::{Matrix, Vector};
+
+/// An affine transform made of a linear part and a translation, which covers scaling, rotation, shearing, and translation.
+/// Points are transformed by the linear part and then translated, while directions ignore the translation.
+///
+/// ```rust
+/// # use nd_vec::{Affine2, Matrix, vector};
+/// let scale = Affine2::from_linear(Matrix::from_diagonal(vector!(2.0, 2.0)));
+/// let transform = Affine2::from_translation(vector!(1.0, 0.0)) * scale;
+///
+/// assert_eq!(transform.transform_point(&vector!(1.0, 1.0)), vector!(3.0, 2.0));
+/// assert_eq!(transform.transform_vector(&vector!(1.0, 1.0)), vector!(2.0, 2.0));
+/// assert_eq!(transform.inverse().unwrap().transform_point(&vector!(3.0, 2.0)), vector!(1.0, 1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Hash)]
+pub struct Affine<T, const N: usize> {
+    /// The linear part of the transform.
+    pub linear: Matrix<T, N, N>,
+    /// The translation applied after the linear part.
+    pub translation: Vector<T, N>,
+}
+
+/// Alias for a 2-dimensional affine transform with the given type.
+pub type Affine2<T> = Affine<T, 2>;
+/// Alias for a 3-dimensional affine transform with the given type.
+pub type Affine3<T> = Affine<T, 3>;
+
+impl<T, const N: usize> Affine<T, N> {
+    /// Creates a new transform from a linear part and a translation.
+    pub const fn new(linear: Matrix<T, N, N>, translation: Vector<T, N>) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Affine<T, N> {
+    /// Creates a new transform that leaves every point unchanged.
+    pub fn identity() -> Self {
+        Self::new(Matrix::identity(), Vector::zero())
+    }
+
+    /// Creates a new transform that only applies a linear map.
+    pub fn from_linear(linear: Matrix<T, N, N>) -> Self {
+        Self::new(linear, Vector::zero())
+    }
+
+    /// Creates a new transform that only translates.
+    pub fn from_translation(translation: Vector<T, N>) -> Self {
+        Self::new(Matrix::identity(), translation)
+    }
+
+    /// Transforms a point, applying both the linear part and the translation.
+    pub fn transform_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *point + self.translation
+    }
+
+    /// Transforms a direction, applying only the linear part.
+    pub fn transform_vector(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *vector
+    }
+
+    /// Combines two transforms into one that applies `other` first and then this transform.
+    /// This is the same as `self * other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self::new(
+            self.linear * other.linear,
+            self.transform_point(&other.translation),
+        )
+    }
+}
+
+impl<T: Real, const N: usize> Affine<T, N> {
+    /// Calculates the transform that undoes this one.
+    /// Returns None if the linear part isn't invertible.
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = self.linear.inverse()?;
+        Some(Self::new(
+            linear,
+            Vector::zero() - linear * self.translation,
+        ))
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Affine<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Affine")
+            .field("linear", &self.linear)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Mul for Affine<T, N> {
+    type Output = Self;
+
+    /// Combines two transforms into one that applies `other` first and then this transform.
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
@@ -0,0 +1,41 @@
+use std::ops::{Deref, DerefMut};
+
+/// A wrapper that aligns its contents to 16 bytes.
+/// Useful for uploading vectors such as `Vector<f32, 4>` to GPU buffers or for use with aligned SIMD loads.
+///
+/// ```rust
+/// # use nd_vec::{Aligned16, vector};
+/// let a = Aligned16(vector!(1.0f32, 2.0, 3.0, 4.0));
+/// assert_eq!(std::mem::align_of_val(&a), 16);
+/// assert_eq!(a.magnitude_squared(), 30.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C, align(16))]
+pub struct Aligned16<T>(pub T);
+
+impl<T> Aligned16<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Aligned16<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for Aligned16<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Aligned16<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
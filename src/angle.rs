@@ -0,0 +1,139 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use num_traits::real::Real;
+
+/// An angle, stored in radians.
+/// Taking angles as this type rather than a raw number makes it impossible to pass degrees where radians are expected.
+///
+/// ```rust
+/// # use nd_vec::Angle;
+/// # use std::f64::consts::FRAC_PI_2;
+/// let angle = Angle::from_degrees(90.0);
+/// assert_eq!(angle.radians(), FRAC_PI_2);
+/// assert_eq!((angle * 2.0).degrees(), 180.0);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd, Hash)]
+pub struct Angle<T> {
+    radians: T,
+}
+
+impl<T> Angle<T> {
+    /// Creates an angle from a value in radians.
+    pub const fn from_radians(radians: T) -> Self {
+        Self { radians }
+    }
+}
+
+impl<T: Real> Angle<T> {
+    /// Creates an angle from a value in degrees.
+    pub fn from_degrees(degrees: T) -> Self {
+        Self::from_radians(degrees.to_radians())
+    }
+
+    /// Returns the angle in radians.
+    pub fn radians(&self) -> T {
+        self.radians
+    }
+
+    /// Returns the angle in degrees.
+    pub fn degrees(&self) -> T {
+        self.radians.to_degrees()
+    }
+
+    /// Calculates the sine of the angle.
+    pub fn sin(&self) -> T {
+        self.radians.sin()
+    }
+
+    /// Calculates the cosine of the angle.
+    pub fn cos(&self) -> T {
+        self.radians.cos()
+    }
+
+    /// Calculates the tangent of the angle.
+    pub fn tan(&self) -> T {
+        self.radians.tan()
+    }
+
+    /// Calculates the sine and cosine of the angle at once.
+    pub fn sin_cos(&self) -> (T, T) {
+        self.radians.sin_cos()
+    }
+
+    /// Returns the same direction as an angle in the range (-π, π].
+    ///
+    /// ```rust
+    /// # use nd_vec::Angle;
+    /// let angle = Angle::from_degrees(270.0f64).wrapped();
+    /// assert!((angle.degrees() + 90.0).abs() < 1e-12);
+    /// ```
+    pub fn wrapped(&self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        let radians = sin.atan2(cos);
+
+        // `atan2` gives -π for a negative zero sine, which is the same direction as π.
+        let pi = T::zero().atan2(-T::one());
+        Self::from_radians(if radians == -pi { pi } else { radians })
+    }
+}
+
+impl<T: Display> Debug for Angle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rad", self.radians)
+    }
+}
+
+impl<T: Real> Add for Angle<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::from_radians(self.radians + other.radians)
+    }
+}
+
+impl<T: Real> Sub for Angle<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::from_radians(self.radians - other.radians)
+    }
+}
+
+impl<T: Real> AddAssign for Angle<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Real> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Real> Neg for Angle<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_radians(-self.radians)
+    }
+}
+
+impl<T: Real> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self::from_radians(self.radians * other)
+    }
+}
+
+impl<T: Real> Div<T> for Angle<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        Self::from_radians(self.radians / other)
+    }
+}
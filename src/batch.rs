@@ -0,0 +1,251 @@
+use std::{
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::Vector;
+
+/// A batch of vectors stored in structure-of-arrays layout.
+/// Each component is stored in its own contiguous lane, which lets the compiler auto-vectorize operations over the whole batch.
+///
+/// ```rust
+/// # use nd_vec::{vector, VectorBatch};
+/// let batch = VectorBatch::from([vector!(1, 2), vector!(3, 4)].as_slice());
+/// assert_eq!(batch.lane(0), &[1, 3]);
+/// assert_eq!((batch * 2).to_vectors(), [vector!(2, 4), vector!(6, 8)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorBatch<T, const N: usize> {
+    lanes: [Vec<T>; N],
+}
+
+impl<T, const N: usize> VectorBatch<T, N> {
+    /// Creates a new empty batch.
+    pub fn new() -> Self {
+        Self {
+            lanes: std::array::from_fn(|_| Vec::new()),
+        }
+    }
+
+    /// Creates a new empty batch with space for at least `capacity` vectors.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            lanes: std::array::from_fn(|_| Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Creates a new batch from one list of values per component.
+    ///
+    /// # Panics
+    /// If the lanes have different lengths.
+    pub fn from_lanes(lanes: [Vec<T>; N]) -> Self {
+        let len = lanes.first().map_or(0, Vec::len);
+        assert!(
+            lanes.iter().all(|x| x.len() == len),
+            "lane lengths must match"
+        );
+        Self { lanes }
+    }
+
+    /// Consumes the batch, returning one list of values per component.
+    pub fn into_lanes(self) -> [Vec<T>; N] {
+        self.lanes
+    }
+
+    /// Returns the number of vectors in the batch.
+    pub fn len(&self) -> usize {
+        self.lanes.first().map_or(0, Vec::len)
+    }
+
+    /// Returns true if the batch contains no vectors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all values of the component with the given index as a slice.
+    ///
+    /// # Panics
+    /// If `axis` is not less than N.
+    pub fn lane(&self, axis: usize) -> &[T] {
+        &self.lanes[axis]
+    }
+
+    /// Returns all values of the component with the given index as a mutable slice.
+    ///
+    /// # Panics
+    /// If `axis` is not less than N.
+    pub fn lane_mut(&mut self, axis: usize) -> &mut [T] {
+        &mut self.lanes[axis]
+    }
+
+    /// Adds a vector to the end of the batch.
+    pub fn push(&mut self, vector: Vector<T, N>) {
+        for (lane, e) in self.lanes.iter_mut().zip(vector.components) {
+            lane.push(e);
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> VectorBatch<T, N> {
+    /// Returns the vector at the given index, or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Vector<T, N>> {
+        (index < self.len()).then(|| Vector::new(std::array::from_fn(|i| self.lanes[i][index])))
+    }
+
+    /// Converts the batch back into a list of vectors.
+    pub fn to_vectors(&self) -> Vec<Vector<T, N>> {
+        (0..self.len())
+            .map(|j| Vector::new(std::array::from_fn(|i| self.lanes[i][j])))
+            .collect()
+    }
+
+    /// Applies a function to every pair of elements of two batches, lane by lane.
+    fn zip_lanes(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        assert_eq!(self.len(), other.len(), "batch lengths must match");
+        Self {
+            lanes: std::array::from_fn(|i| {
+                let (a, b) = (&self.lanes[i], &other.lanes[i]);
+                a.iter().zip(b.iter()).map(|(&a, &b)| f(a, b)).collect()
+            }),
+        }
+    }
+
+    /// Applies a function to every element of the batch along with the matching component of `vector`.
+    fn map_lanes(&self, vector: &Vector<T, N>, f: impl Fn(T, T) -> T) -> Self {
+        Self {
+            lanes: std::array::from_fn(|i| {
+                let b = vector.components[i];
+                self.lanes[i].iter().map(|&a| f(a, b)).collect()
+            }),
+        }
+    }
+}
+
+impl<T: Num + Copy, const N: usize> VectorBatch<T, N> {
+    /// Computes the Hadamard product (component-wise multiplication) of every pair of vectors in two batches.
+    ///
+    /// # Panics
+    /// If the batches have different lengths.
+    pub fn hadamard_product(&self, other: &Self) -> Self {
+        self.zip_lanes(other, |a, b| a * b)
+    }
+
+    /// Calculates the dot product of every pair of vectors in two batches.
+    ///
+    /// # Panics
+    /// If the batches have different lengths.
+    pub fn dot(&self, other: &Self) -> Vec<T> {
+        assert_eq!(self.len(), other.len(), "batch lengths must match");
+        let mut out = vec![T::zero(); self.len()];
+        for (a, b) in self.lanes.iter().zip(other.lanes.iter()) {
+            for ((o, &a), &b) in out.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *o = *o + a * b;
+            }
+        }
+        out
+    }
+
+    /// Calculates the sum of all squared components of every vector in the batch.
+    pub fn magnitude_squared(&self) -> Vec<T> {
+        self.dot(self)
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> VectorBatch<T, N> {
+    /// Calculates the magnitude of every vector in the batch.
+    pub fn magnitude(&self) -> Vec<T> {
+        let mut out = self.magnitude_squared();
+        out.iter_mut().for_each(|x| *x = x.sqrt());
+        out
+    }
+
+    /// Normalizes every vector in the batch.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.magnitude();
+        Self {
+            lanes: std::array::from_fn(|i| {
+                let lane = self.lanes[i].iter().zip(magnitude.iter());
+                lane.map(|(&a, &m)| a / m).collect()
+            }),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for VectorBatch<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> From<&[Vector<T, N>]> for VectorBatch<T, N> {
+    fn from(vectors: &[Vector<T, N>]) -> Self {
+        let mut batch = Self::with_capacity(vectors.len());
+        vectors.iter().for_each(|x| batch.push(*x));
+        batch
+    }
+}
+
+impl<T: Copy, const N: usize> From<&VectorBatch<T, N>> for Vec<Vector<T, N>> {
+    fn from(batch: &VectorBatch<T, N>) -> Self {
+        batch.to_vectors()
+    }
+}
+
+impl<T, const N: usize> FromIterator<Vector<T, N>> for VectorBatch<T, N> {
+    fn from_iter<I: IntoIterator<Item = Vector<T, N>>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        iter.into_iter().for_each(|x| batch.push(x));
+        batch
+    }
+}
+
+macro_rules! batch_op {
+    ($trait:tt, $func:ident, $op:tt) => {
+        impl<T: Num + Copy, const N: usize> $trait for VectorBatch<T, N> {
+            type Output = Self;
+
+            /// # Panics
+            /// If the batches have different lengths.
+            fn $func(self, other: Self) -> Self::Output {
+                self.zip_lanes(&other, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Num + Copy, const N: usize> $trait<Vector<T, N>> for VectorBatch<T, N> {
+            type Output = Self;
+
+            fn $func(self, other: Vector<T, N>) -> Self::Output {
+                self.map_lanes(&other, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Num + Copy, const N: usize> $trait<T> for VectorBatch<T, N> {
+            type Output = Self;
+
+            fn $func(self, other: T) -> Self::Output {
+                self.map_lanes(&Vector::new([other; N]), |a, b| a $op b)
+            }
+        }
+    };
+}
+
+batch_op!(Add, add, +);
+batch_op!(Sub, sub, -);
+
+impl<T: Num + Copy, const N: usize> Mul<T> for VectorBatch<T, N> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        self.map_lanes(&Vector::new([other; N]), |a, b| a * b)
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Div<T> for VectorBatch<T, N> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        self.map_lanes(&Vector::new([other; N]), |a, b| a / b)
+    }
+}
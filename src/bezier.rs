@@ -0,0 +1,129 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::Vector;
+
+/// A [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve) over N-dimensional control points.
+/// The curve starts at the first control point at `t = 0` and ends at the last one at `t = 1`.
+/// Curves of any degree are supported, with [`Bezier::quadratic`] and [`Bezier::cubic`] covering the common cases.
+///
+/// ```rust
+/// # use nd_vec::{Bezier, vector};
+/// let curve = Bezier::quadratic(vector!(0.0, 0.0), vector!(1.0, 2.0), vector!(2.0, 0.0));
+/// assert_eq!(curve.eval(0.5), vector!(1.0, 1.0));
+/// assert_eq!(curve.derivative(0.5), vector!(2.0, 0.0));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Bezier<T, const N: usize> {
+    points: Vec<Vector<T, N>>,
+}
+
+impl<T: Real, const N: usize> Bezier<T, N> {
+    /// Creates a new curve from its control points.
+    /// The degree of the curve is one less than the number of points.
+    ///
+    /// # Panics
+    /// If no control points are given.
+    pub fn new(points: Vec<Vector<T, N>>) -> Self {
+        assert!(
+            !points.is_empty(),
+            "a curve needs at least one control point"
+        );
+        Self { points }
+    }
+
+    /// Creates a quadratic curve from a start point, a control point, and an end point.
+    pub fn quadratic(start: Vector<T, N>, control: Vector<T, N>, end: Vector<T, N>) -> Self {
+        Self::new(vec![start, control, end])
+    }
+
+    /// Creates a cubic curve from a start point, two control points, and an end point.
+    pub fn cubic(
+        start: Vector<T, N>,
+        control_a: Vector<T, N>,
+        control_b: Vector<T, N>,
+        end: Vector<T, N>,
+    ) -> Self {
+        Self::new(vec![start, control_a, control_b, end])
+    }
+
+    /// Returns the control points of the curve.
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+
+    /// Returns the degree of the curve, which is 2 for quadratic and 3 for cubic curves.
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Evaluates the point on the curve at `t`, using [De Casteljau's algorithm](https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm).
+    pub fn eval(&self, t: T) -> Vector<T, N> {
+        let mut points = self.points.clone();
+        for len in (1..points.len()).rev() {
+            for i in 0..len {
+                points[i] = points[i].lerp(&points[i + 1], t);
+            }
+        }
+        points[0]
+    }
+
+    /// Evaluates the tangent of the curve at `t`, the derivative of the position with respect to `t`.
+    pub fn derivative(&self, t: T) -> Vector<T, N> {
+        self.hodograph().map_or(Vector::zero(), |x| x.eval(t))
+    }
+
+    /// Splits the curve at `t` into two curves of the same degree, which together trace the same path.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let mut points = self.points.clone();
+        let (mut left, mut right) = (vec![points[0]], vec![points[points.len() - 1]]);
+        for len in (1..points.len()).rev() {
+            for i in 0..len {
+                points[i] = points[i].lerp(&points[i + 1], t);
+            }
+            left.push(points[0]);
+            right.push(points[len - 1]);
+        }
+
+        right.reverse();
+        (Self::new(left), Self::new(right))
+    }
+
+    /// Calculates the curve describing the derivative, with one less degree.
+    /// Returns None for a single point, which has no derivative curve.
+    fn hodograph(&self) -> Option<Self> {
+        let scale = T::from(self.degree()).unwrap();
+        let points = self
+            .points
+            .windows(2)
+            .map(|x| Vector::new((x[1] - x[0]).components.map(|e| e * scale)))
+            .collect::<Vec<_>>();
+        (!points.is_empty()).then(|| Self::new(points))
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> Bezier<T, N> {
+    /// Approximates the length of the curve by summing the lengths of `segments` straight lines between evenly spaced points.
+    /// More segments give a more accurate result.
+    pub fn arc_length(&self, segments: usize) -> T {
+        let count = T::from(segments.max(1)).unwrap();
+        let mut previous = self.eval(T::zero());
+        let mut out = T::zero();
+        for i in 1..=segments.max(1) {
+            let point = self.eval(T::from(i).unwrap() / count);
+            out = out + point.distance(&previous);
+            previous = point;
+        }
+        out
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Bezier<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Bezier").field(&self.points).finish()
+    }
+}
@@ -0,0 +1,19 @@
+//! [defmt](https://defmt.ferrous-systems.com) support for logging vectors on embedded targets.
+
+use defmt::{Format, Formatter};
+
+use crate::Vector;
+
+impl<T: Format, const N: usize> Format for Vector<T, N> {
+    /// Formats the vector like its `Debug` implementation, for example `(1, 2, 3)`.
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "(");
+        for (i, e) in self.components.iter().enumerate() {
+            if i != 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}", e);
+        }
+        defmt::write!(f, ")");
+    }
+}
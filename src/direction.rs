@@ -0,0 +1,165 @@
+use num_traits::{Num, Signed};
+
+use crate::Vector;
+
+/// A direction on a 2D grid, including the diagonals.
+/// North points towards positive y and east towards positive x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction2 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// A direction in a 3D grid.
+/// North points towards positive y, east towards positive x, and up towards positive z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    North,
+    East,
+    South,
+    West,
+    Up,
+    Down,
+}
+
+impl Direction2 {
+    /// All directions, in clockwise order starting from north.
+    pub const ALL: [Direction2; 8] = [
+        Direction2::North,
+        Direction2::NorthEast,
+        Direction2::East,
+        Direction2::SouthEast,
+        Direction2::South,
+        Direction2::SouthWest,
+        Direction2::West,
+        Direction2::NorthWest,
+    ];
+
+    /// The four cardinal directions, in clockwise order starting from north.
+    pub const CARDINAL: [Direction2; 4] = [
+        Direction2::North,
+        Direction2::East,
+        Direction2::South,
+        Direction2::West,
+    ];
+
+    /// Rotates the direction by the given number of 45° steps clockwise.
+    fn rotate(self, steps: usize) -> Self {
+        Self::ALL[(self as usize + steps) % 8]
+    }
+
+    /// Rotates the direction 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Rotates the direction 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the direction pointing the opposite way.
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns true if the direction is one of the four diagonals.
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Converts the direction into a vector with each component being -1, 0, or 1.
+    /// Note that diagonal directions are not normalized.
+    pub fn as_vector<T: Num + Signed + Copy>(self) -> Vector<T, 2> {
+        let (o, z) = (T::one(), T::zero());
+        Vector::new(match self {
+            Direction2::North => [z, o],
+            Direction2::NorthEast => [o, o],
+            Direction2::East => [o, z],
+            Direction2::SouthEast => [o, -o],
+            Direction2::South => [z, -o],
+            Direction2::SouthWest => [-o, -o],
+            Direction2::West => [-o, z],
+            Direction2::NorthWest => [-o, o],
+        })
+    }
+}
+
+impl Direction3 {
+    /// All six directions.
+    pub const ALL: [Direction3; 6] = [
+        Direction3::North,
+        Direction3::East,
+        Direction3::South,
+        Direction3::West,
+        Direction3::Up,
+        Direction3::Down,
+    ];
+
+    /// Rotates the direction 90° counter-clockwise around the up axis.
+    /// Up and down are left unchanged.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction3::North => Direction3::West,
+            Direction3::East => Direction3::North,
+            Direction3::South => Direction3::East,
+            Direction3::West => Direction3::South,
+            x => x,
+        }
+    }
+
+    /// Rotates the direction 90° clockwise around the up axis.
+    /// Up and down are left unchanged.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction3::North => Direction3::East,
+            Direction3::East => Direction3::South,
+            Direction3::South => Direction3::West,
+            Direction3::West => Direction3::North,
+            x => x,
+        }
+    }
+
+    /// Returns the direction pointing the opposite way.
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction3::North => Direction3::South,
+            Direction3::East => Direction3::West,
+            Direction3::South => Direction3::North,
+            Direction3::West => Direction3::East,
+            Direction3::Up => Direction3::Down,
+            Direction3::Down => Direction3::Up,
+        }
+    }
+
+    /// Converts the direction into a unit vector.
+    pub fn as_vector<T: Num + Signed + Copy>(self) -> Vector<T, 3> {
+        let (o, z) = (T::one(), T::zero());
+        Vector::new(match self {
+            Direction3::North => [z, o, z],
+            Direction3::East => [o, z, z],
+            Direction3::South => [z, -o, z],
+            Direction3::West => [-o, z, z],
+            Direction3::Up => [z, z, o],
+            Direction3::Down => [z, z, -o],
+        })
+    }
+}
+
+impl<T: Num + Signed + Copy> From<Direction2> for Vector<T, 2> {
+    fn from(direction: Direction2) -> Self {
+        direction.as_vector()
+    }
+}
+
+impl<T: Num + Signed + Copy> From<Direction3> for Vector<T, 3> {
+    fn from(direction: Direction3) -> Self {
+        direction.as_vector()
+    }
+}
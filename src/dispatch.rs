@@ -0,0 +1,53 @@
+//! Picks concrete implementations for the generic vector operators, used by the `strict` feature.
+//!
+//! The operators are generic over the component type and can't be specialized, so the concrete types are found by comparing type ids.
+//! Both the component type and the dimension are known at compile time, so the checks are free and the unused paths are removed.
+
+/// A binary operator applied to each pair of components.
+#[derive(Clone, Copy)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// Checks if `T` is the type `U`.
+/// Unlike comparing [`TypeId`](std::any::TypeId)s directly this doesn't need `T: 'static`, which the operators don't require.
+#[inline(always)]
+pub(crate) fn is<T, U: 'static>() -> bool {
+    use std::{any::TypeId, marker::PhantomData};
+
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = PhantomData::<T>;
+    // SAFETY: Lifetimes don't affect type ids and `U: 'static` has none, so erasing them can't cause a false match.
+    let phantom = unsafe {
+        std::mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom)
+    };
+    phantom.type_id() == TypeId::of::<U>()
+}
+
+/// Reinterprets a value as another type.
+///
+/// # Safety
+/// `A` and `B` must be the same type.
+#[inline(always)]
+pub(crate) unsafe fn cast<A, B>(value: A) -> B {
+    let value = std::mem::ManuallyDrop::new(value);
+    std::mem::transmute_copy(&*value)
+}
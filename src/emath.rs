@@ -0,0 +1,29 @@
+//! Conversions between vectors and the [emath](https://docs.rs/emath) types used by [egui](https://docs.rs/egui).
+
+use emath::{Pos2, Vec2};
+
+use crate::Vector;
+
+impl From<Vector<f32, 2>> for Vec2 {
+    fn from(vector: Vector<f32, 2>) -> Self {
+        Vec2::new(vector.x(), vector.y())
+    }
+}
+
+impl From<Vec2> for Vector<f32, 2> {
+    fn from(vec: Vec2) -> Self {
+        Vector::new([vec.x, vec.y])
+    }
+}
+
+impl From<Vector<f32, 2>> for Pos2 {
+    fn from(vector: Vector<f32, 2>) -> Self {
+        Pos2::new(vector.x(), vector.y())
+    }
+}
+
+impl From<Pos2> for Vector<f32, 2> {
+    fn from(pos: Pos2) -> Self {
+        Vector::new([pos.x, pos.y])
+    }
+}
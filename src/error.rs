@@ -0,0 +1,28 @@
+use std::{error::Error, fmt::Display};
+
+/// Error returned when an iterator yields the wrong number of components to build a vector.
+/// See [`Vector::try_from_iter`](crate::Vector::try_from_iter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrongLength {
+    /// The number of components the vector has.
+    pub expected: usize,
+    /// The number of items the iterator yielded.
+    /// Iteration stops after one item more than expected, so this is at most `expected + 1`.
+    pub found: usize,
+}
+
+impl Display for WrongLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.found > self.expected {
+            write!(f, "expected {} components, found more", self.expected)
+        } else {
+            write!(
+                f,
+                "expected {} components, found {}",
+                self.expected, self.found
+            )
+        }
+    }
+}
+
+impl Error for WrongLength {}
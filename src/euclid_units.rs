@@ -0,0 +1,50 @@
+//! Conversions between vectors and [euclid](https://docs.rs/euclid) points, vectors, and sizes.
+//!
+//! Euclid tags its types with a phantom unit parameter to keep values from different coordinate spaces apart.
+//! Vectors have no unit, so converting into a euclid type works for any unit, chosen by the type being converted into, and converting back drops the unit.
+//! Use [`euclid::Vector2D::cast_unit`] and friends on the euclid side to move between units explicitly.
+
+use euclid::{Point2D, Point3D, Size2D, Size3D, Vector2D, Vector3D};
+
+use crate::Vector;
+
+macro_rules! euclid_2d {
+    ($type:ident, $x:ident, $y:ident) => {
+        impl<T, U> From<Vector<T, 2>> for $type<T, U> {
+            fn from(vector: Vector<T, 2>) -> Self {
+                let [x, y] = vector.components;
+                $type::new(x, y)
+            }
+        }
+
+        impl<T, U> From<$type<T, U>> for Vector<T, 2> {
+            fn from(value: $type<T, U>) -> Self {
+                Vector::new([value.$x, value.$y])
+            }
+        }
+    };
+}
+
+macro_rules! euclid_3d {
+    ($type:ident, $x:ident, $y:ident, $z:ident) => {
+        impl<T, U> From<Vector<T, 3>> for $type<T, U> {
+            fn from(vector: Vector<T, 3>) -> Self {
+                let [x, y, z] = vector.components;
+                $type::new(x, y, z)
+            }
+        }
+
+        impl<T, U> From<$type<T, U>> for Vector<T, 3> {
+            fn from(value: $type<T, U>) -> Self {
+                Vector::new([value.$x, value.$y, value.$z])
+            }
+        }
+    };
+}
+
+euclid_2d!(Vector2D, x, y);
+euclid_2d!(Point2D, x, y);
+euclid_2d!(Size2D, width, height);
+euclid_3d!(Vector3D, x, y, z);
+euclid_3d!(Point3D, x, y, z);
+euclid_3d!(Size3D, width, height, depth);
@@ -0,0 +1,60 @@
+//! Conversions between vectors and [geo-types](https://docs.rs/geo-types) coordinates, along with [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry) output.
+
+use std::fmt::Display;
+
+use geo_types::{Coord, CoordNum, Point};
+
+use crate::Vector;
+
+impl<T: CoordNum> From<Vector<T, 2>> for Coord<T> {
+    fn from(vector: Vector<T, 2>) -> Self {
+        Coord {
+            x: vector.x(),
+            y: vector.y(),
+        }
+    }
+}
+
+impl<T: CoordNum> From<Coord<T>> for Vector<T, 2> {
+    fn from(coord: Coord<T>) -> Self {
+        Vector::new([coord.x, coord.y])
+    }
+}
+
+impl<T: CoordNum> From<Vector<T, 2>> for Point<T> {
+    fn from(vector: Vector<T, 2>) -> Self {
+        Point::new(vector.x(), vector.y())
+    }
+}
+
+impl<T: CoordNum> From<Point<T>> for Vector<T, 2> {
+    fn from(point: Point<T>) -> Self {
+        Vector::new([point.x(), point.y()])
+    }
+}
+
+impl<T: Display> Vector<T, 2> {
+    /// Formats the vector as a [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry) point.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1.5, -2.0).to_wkt(), "POINT(1.5 -2)");
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        let [x, y] = &self.components;
+        format!("POINT({x} {y})")
+    }
+}
+
+impl<T: Display> Vector<T, 3> {
+    /// Formats the vector as a three-dimensional [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry) point.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1, 2, 3).to_wkt(), "POINT Z(1 2 3)");
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        let [x, y, z] = &self.components;
+        format!("POINT Z({x} {y} {z})")
+    }
+}
@@ -0,0 +1,264 @@
+//! Computational geometry algorithms operating on points, segments, triangles, and polygons.
+
+use std::iter::Sum;
+
+use num_traits::{real::Real, Num, Signed};
+
+use crate::Vector;
+
+/// Calculates the z component of the cross product of `a - origin` and `b - origin`.
+/// This is positive if `origin`, `a`, `b` are in counter-clockwise order, negative if clockwise, and zero if collinear.
+fn cross<T: Num + Copy>(origin: Vector<T, 2>, a: Vector<T, 2>, b: Vector<T, 2>) -> T {
+    let (a, b) = (a - origin, b - origin);
+    a.x() * b.y() - a.y() * b.x()
+}
+
+/// Computes the convex hull of a set of points using [Andrew's monotone chain algorithm](https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain).
+/// The hull is returned in counter-clockwise order, starting with the point with the smallest x (then y) coordinate.
+/// Collinear points on the edges of the hull are not included.
+///
+/// ```rust
+/// # use nd_vec::{geometry::convex_hull, vector};
+/// let points = [vector!(0, 0), vector!(2, 0), vector!(1, 1), vector!(2, 2), vector!(0, 2)];
+/// assert_eq!(convex_hull(&points), [vector!(0, 0), vector!(2, 0), vector!(2, 2), vector!(0, 2)]);
+/// ```
+pub fn convex_hull<T: Num + Copy + Signed + PartialOrd>(
+    points: &[Vector<T, 2>],
+) -> Vec<Vector<T, 2>> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| {
+        (a.x(), a.y())
+            .partial_cmp(&(b.x(), b.y()))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull = Vec::with_capacity(points.len() + 1);
+    half_hull(&mut hull, points.iter());
+    half_hull(&mut hull, points.iter().rev());
+    hull
+}
+
+/// Appends one half (lower or upper) of the convex hull of the sorted points to `hull`.
+fn half_hull<'a, T: Num + Copy + Signed + PartialOrd + 'a>(
+    hull: &mut Vec<Vector<T, 2>>,
+    points: impl Iterator<Item = &'a Vector<T, 2>>,
+) {
+    let base = hull.len();
+    for &p in points {
+        while hull.len() >= base + 2
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= T::zero()
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // The last point of each half is the first point of the other
+    hull.pop();
+}
+
+/// Calculates the barycentric coordinates of `p` with respect to the triangle `a`, `b`, `c`.
+/// The result holds the weights of `a`, `b`, and `c`, which sum to one and reconstruct `p` as `a * u + b * v + c * w`.
+/// The point is inside the triangle if all weights are between zero and one.
+///
+/// For 3D triangles the point is first projected onto the plane of the triangle.
+/// Degenerate triangles divide by zero, giving NaN weights.
+///
+/// ```rust
+/// # use nd_vec::{geometry::barycentric, vector};
+/// let weights = barycentric(&vector!(1.0, 1.0), &vector!(0.0, 0.0), &vector!(4.0, 0.0), &vector!(0.0, 4.0));
+/// assert_eq!(weights, vector!(0.5, 0.25, 0.25));
+/// ```
+pub fn barycentric<T: Num + Copy + Sum + Real, const N: usize>(
+    p: &Vector<T, N>,
+    a: &Vector<T, N>,
+    b: &Vector<T, N>,
+    c: &Vector<T, N>,
+) -> Vector<T, 3> {
+    let (ab, ac, ap) = (*b - *a, *c - *a, *p - *a);
+    let (d00, d01, d11) = (ab.dot(&ab), ab.dot(&ac), ac.dot(&ac));
+    let (d20, d21) = (ap.dot(&ab), ap.dot(&ac));
+
+    let denominator = d00 * d11 - d01 * d01;
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+    Vector::new([T::one() - v - w, v, w])
+}
+
+/// Interpolates per-vertex attributes of a triangle, like colors or texture coordinates, with barycentric weights.
+///
+/// ```rust
+/// # use nd_vec::{geometry::barycentric_interpolate, vector};
+/// let uv = barycentric_interpolate(&vector!(0.5, 0.25, 0.25), &[vector!(0.0, 0.0), vector!(1.0, 0.0), vector!(0.0, 1.0)]);
+/// assert_eq!(uv, vector!(0.25, 0.25));
+/// ```
+pub fn barycentric_interpolate<T: Num + Copy, const N: usize>(
+    weights: &Vector<T, 3>,
+    attributes: &[Vector<T, N>; 3],
+) -> Vector<T, N> {
+    let mut out = Vector::zero();
+    for (&weight, attribute) in weights.components.iter().zip(attributes) {
+        for (e, &x) in out.components.iter_mut().zip(attribute.components.iter()) {
+            *e = *e + x * weight;
+        }
+    }
+    out
+}
+
+/// Checks if a point is inside a polygon using the [winding number](https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm) method.
+/// The polygon is closed automatically, so the last point connects back to the first, and may be given in either orientation.
+/// Self-intersecting polygons are supported, with every region the boundary winds around counted as inside.
+/// Points on the boundary are considered inside.
+///
+/// Only comparisons and products are used, so the result is exact for integer coordinates.
+///
+/// ```rust
+/// # use nd_vec::{geometry::point_in_polygon, vector};
+/// // An L shape
+/// let polygon = [vector!(0, 0), vector!(2, 0), vector!(2, 1), vector!(1, 1), vector!(1, 2), vector!(0, 2)];
+/// assert!(point_in_polygon(&vector!(1, 0), &polygon));
+/// assert!(!point_in_polygon(&vector!(2, 2), &polygon));
+/// ```
+pub fn point_in_polygon<T: Num + Copy + PartialOrd>(
+    point: &Vector<T, 2>,
+    polygon: &[Vector<T, 2>],
+) -> bool {
+    let p = *point;
+    let mut winding = 0;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let side = cross(a, b, p);
+
+        let within = |x: T, y: T, z: T| (x <= z && z <= y) || (y <= z && z <= x);
+        if side == T::zero() && within(a.x(), b.x(), p.x()) && within(a.y(), b.y(), p.y()) {
+            return true;
+        }
+
+        if a.y() <= p.y() {
+            if b.y() > p.y() && side > T::zero() {
+                winding += 1;
+            }
+        } else if b.y() <= p.y() && side < T::zero() {
+            winding -= 1;
+        }
+    }
+    winding != 0
+}
+
+/// Calculates the signed area of a polygon using the [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula).
+/// The area is positive if the points are in counter-clockwise order and negative if they are clockwise, assuming the y axis points up.
+/// The polygon is closed automatically, so the last point connects back to the first.
+///
+/// For self-intersecting polygons, regions wound in opposite directions cancel out.
+/// For integer coordinates the result is rounded towards zero, as it can be a multiple of one half.
+///
+/// ```rust
+/// # use nd_vec::{geometry::{polygon_area, polygon_signed_area}, vector};
+/// let square = [vector!(0.0, 0.0), vector!(2.0, 0.0), vector!(2.0, 2.0), vector!(0.0, 2.0)];
+/// assert_eq!(polygon_signed_area(&square), 4.0);
+///
+/// let clockwise = [vector!(0.0, 0.0), vector!(0.0, 2.0), vector!(2.0, 2.0), vector!(2.0, 0.0)];
+/// assert_eq!(polygon_signed_area(&clockwise), -4.0);
+/// assert_eq!(polygon_area(&clockwise), 4.0);
+/// ```
+pub fn polygon_signed_area<T: Num + Copy>(polygon: &[Vector<T, 2>]) -> T {
+    let mut twice_area = T::zero();
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        twice_area = twice_area + (a.x() * b.y() - a.y() * b.x());
+    }
+    twice_area / (T::one() + T::one())
+}
+
+/// Calculates the area of a polygon, regardless of the order of its points.
+/// See [`polygon_signed_area`] for details.
+pub fn polygon_area<T: Num + Copy + Signed>(polygon: &[Vector<T, 2>]) -> T {
+    polygon_signed_area(polygon).abs()
+}
+
+/// Calculates the centroid of the area enclosed by a polygon, its center of mass assuming uniform density.
+/// Unlike the mean of the points, this isn't skewed by regions with many points.
+/// Returns None if the polygon has no area.
+///
+/// ```rust
+/// # use nd_vec::{geometry::polygon_centroid, vector};
+/// let triangle = [vector!(0.0, 0.0), vector!(3.0, 0.0), vector!(0.0, 3.0)];
+/// assert_eq!(polygon_centroid(&triangle), Some(vector!(1.0, 1.0)));
+/// ```
+pub fn polygon_centroid<T: Real>(polygon: &[Vector<T, 2>]) -> Option<Vector<T, 2>> {
+    let area = polygon_signed_area(polygon);
+    if area == T::zero() {
+        return None;
+    }
+
+    let (mut x, mut y) = (T::zero(), T::zero());
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let cross = a.x() * b.y() - a.y() * b.x();
+        x = x + (a.x() + b.x()) * cross;
+        y = y + (a.y() + b.y()) * cross;
+    }
+
+    let scale = T::from(6).unwrap() * area;
+    Some(Vector::new([x / scale, y / scale]))
+}
+
+/// Finds the point where the segment from `a1` to `a2` intersects the segment from `b1` to `b2`, including their endpoints.
+/// Returns None if the segments don't touch, including when they are parallel but not on the same line.
+///
+/// Collinear segments that overlap share more than one point, in which case the shared point closest to `a1` is returned.
+/// Segments of zero length are treated as points.
+///
+/// ```rust
+/// # use nd_vec::{geometry::segment_intersection, vector};
+/// let (a1, a2) = (vector!(0.0, 0.0), vector!(2.0, 2.0));
+/// assert_eq!(segment_intersection(&a1, &a2, &vector!(0.0, 2.0), &vector!(2.0, 0.0)), Some(vector!(1.0, 1.0)));
+/// assert_eq!(segment_intersection(&a1, &a2, &vector!(1.0, 0.0), &vector!(3.0, 2.0)), None);
+///
+/// // Collinear and overlapping
+/// assert_eq!(segment_intersection(&a1, &a2, &vector!(3.0, 3.0), &vector!(1.0, 1.0)), Some(vector!(1.0, 1.0)));
+/// ```
+pub fn segment_intersection<T: Real>(
+    a1: &Vector<T, 2>,
+    a2: &Vector<T, 2>,
+    b1: &Vector<T, 2>,
+    b2: &Vector<T, 2>,
+) -> Option<Vector<T, 2>> {
+    let (r, s, offset) = (*a2 - *a1, *b2 - *b1, *b1 - *a1);
+    let origin = Vector::zero();
+    let denominator = cross(origin, r, s);
+    let unit = |t: T| t >= T::zero() && t <= T::one();
+
+    if denominator != T::zero() {
+        let t = cross(origin, offset, s) / denominator;
+        let u = cross(origin, offset, r) / denominator;
+        return (unit(t) && unit(u)).then(|| *a1 + r.map(|x| x * t));
+    }
+
+    // Parallel segments only meet if they are on the same line
+    if cross(origin, offset, r) != T::zero() || cross(origin, offset, s) != T::zero() {
+        return None;
+    }
+
+    let length = r.x() * r.x() + r.y() * r.y();
+    if length == T::zero() {
+        // The first segment is a single point
+        let length = s.x() * s.x() + s.y() * s.y();
+        if length == T::zero() {
+            return (a1 == b1).then_some(*a1);
+        }
+        let t = -(offset.x() * s.x() + offset.y() * s.y()) / length;
+        return unit(t).then_some(*a1);
+    }
+
+    // Find where the second segment starts and ends along the first
+    let t0 = (offset.x() * r.x() + offset.y() * r.y()) / length;
+    let t1 = t0 + (s.x() * r.x() + s.y() * r.y()) / length;
+    let (start, end) = (t0.min(t1).max(T::zero()), t0.max(t1).min(T::one()));
+    (start <= end).then(|| *a1 + r.map(|x| x * start))
+}
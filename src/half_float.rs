@@ -0,0 +1,54 @@
+//! Support for half precision [`f16`] and [`bf16`] components, powered by [half](https://docs.rs/half).
+//!
+//! Half precision vectors work with all of the generic vector math, but intermediate results like the sum of squares in [`Vector::magnitude`] easily overflow or lose precision.
+//! The `widened_*` methods do their math in `f64` and only round the final result back to half precision.
+
+use half::{bf16, f16};
+
+use crate::Vector;
+
+macro_rules! half_float {
+    ($type:ty) => {
+        impl<const N: usize> Vector<$type, N> {
+            /// Converts each component to a `f32`, which is lossless.
+            pub fn to_f32(&self) -> Vector<f32, N> {
+                Vector::new(self.components.map(<$type>::to_f32))
+            }
+
+            #[doc = concat!("Converts each component of a `f32` vector to a `", stringify!($type), "`, rounding to the nearest representable value.")]
+            pub fn from_f32(vector: &Vector<f32, N>) -> Self {
+                Self::new(vector.components.map(<$type>::from_f32))
+            }
+
+            /// Calculates the dot product of two vectors in `f64`, so the result is only rounded once.
+            pub fn widened_dot(&self, other: &Self) -> $type {
+                let products = self.components.iter().zip(other.components.iter());
+                <$type>::from_f64(products.map(|(a, b)| a.to_f64() * b.to_f64()).sum())
+            }
+
+            /// Calculates the magnitude of a vector in `f64`, so squaring the components doesn't overflow.
+            pub fn widened_magnitude(&self) -> $type {
+                <$type>::from_f64(self.widened_magnitude_f64())
+            }
+
+            /// Normalizes a vector in `f64`, so squaring the components doesn't overflow.
+            pub fn widened_normalize(&self) -> Self {
+                let magnitude = self.widened_magnitude_f64();
+                Self::new(self.components.map(|x| <$type>::from_f64(x.to_f64() / magnitude)))
+            }
+
+            fn widened_magnitude_f64(&self) -> f64 {
+                self.components.iter().map(|x| x.to_f64() * x.to_f64()).sum::<f64>().sqrt()
+            }
+        }
+
+        impl<const N: usize> From<Vector<$type, N>> for Vector<f32, N> {
+            fn from(vector: Vector<$type, N>) -> Self {
+                vector.to_f32()
+            }
+        }
+    };
+}
+
+half_float!(f16);
+half_float!(bf16);
@@ -0,0 +1,122 @@
+//! Hilbert curve index conversion using John Skilling's transpose algorithm.
+//! See "Programming the Hilbert curve" (AIP Conference Proceedings 707, 2004).
+
+use crate::Vector;
+
+/// Converts axis coordinates into the "transposed" Hilbert index in place.
+fn axes_to_transpose<const N: usize>(x: &mut [u64; N], order: u32) {
+    // Inverse undo
+    for bit in (1..order).rev() {
+        let (q, p) = (1 << bit, (1 << bit) - 1);
+        for i in 0..N {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+    }
+
+    // Gray encode
+    for i in 1..N {
+        x[i] ^= x[i - 1];
+    }
+
+    let mut t = 0;
+    for bit in 1..order {
+        if x[N - 1] & (1 << bit) != 0 {
+            t ^= (1 << bit) - 1;
+        }
+    }
+
+    for e in x.iter_mut() {
+        *e ^= t;
+    }
+}
+
+/// Converts a "transposed" Hilbert index back into axis coordinates in place.
+fn transpose_to_axes<const N: usize>(x: &mut [u64; N], order: u32) {
+    // Gray decode
+    let t = x[N - 1] >> 1;
+    for i in (1..N).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+
+    // Undo excess work
+    for bit in 1..order {
+        let (q, p) = (1 << bit, (1 << bit) - 1);
+        for i in (0..N).rev() {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+    }
+}
+
+fn interleave<const N: usize>(x: &[u64; N], order: u32) -> u128 {
+    let mut index = 0;
+    for bit in (0..order).rev() {
+        for e in x {
+            index = (index << 1) | ((e >> bit) & 1) as u128;
+        }
+    }
+    index
+}
+
+fn deinterleave<const N: usize>(mut index: u128, order: u32) -> [u64; N] {
+    let mut x = [0; N];
+    for bit in 0..order {
+        for e in x.iter_mut().rev() {
+            *e |= ((index & 1) as u64) << bit;
+            index >>= 1;
+        }
+    }
+    x
+}
+
+macro_rules! hilbert {
+    ($type:ty, $n:literal, $key:ty) => {
+        impl Vector<$type, $n> {
+            /// Converts the vector into its index along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve) filling a grid with a side length of `2^order`.
+            /// All components must be less than `2^order`, higher bits are ignored.
+            ///
+            /// # Panics
+            /// If `order` is zero or greater than the bit width of the component type.
+            pub fn to_hilbert(&self, order: u32) -> $key {
+                assert!(order > 0 && order <= <$type>::BITS, "invalid hilbert order");
+                let mask = u64::MAX >> (64 - order);
+                let mut x = self.components.map(|e| e as u64 & mask);
+                axes_to_transpose(&mut x, order);
+                interleave(&x, order) as $key
+            }
+
+            /// Converts an index along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve) filling a grid with a side length of `2^order` back into a vector.
+            /// This is the inverse of [`Vector::to_hilbert`].
+            ///
+            /// # Panics
+            /// If `order` is zero or greater than the bit width of the component type.
+            pub fn from_hilbert(index: $key, order: u32) -> Self {
+                assert!(order > 0 && order <= <$type>::BITS, "invalid hilbert order");
+                let mut x = deinterleave::<$n>(index as u128, order);
+                transpose_to_axes(&mut x, order);
+                Self::new(x.map(|e| e as $type))
+            }
+        }
+    };
+}
+
+hilbert!(u8, 2, u16);
+hilbert!(u16, 2, u32);
+hilbert!(u32, 2, u64);
+hilbert!(u64, 2, u128);
+
+hilbert!(u8, 3, u32);
+hilbert!(u16, 3, u64);
+hilbert!(u32, 3, u128);
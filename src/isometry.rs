@@ -0,0 +1,161 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Mul,
+};
+
+use num_traits::real::Real;
+
+use crate::{Affine, Matrix, Rotation2, Rotation3, Vector};
+
+/// A rigid body transform made of a rotation followed by a translation, also known as a pose.
+/// Isometries preserve distances and angles, and unlike general [`Affine`] transforms their inverse always exists and is cheap to calculate.
+///
+/// ```rust
+/// # use nd_vec::{Angle, Isometry2, Rotation2, vector};
+/// let pose = Isometry2::new(Rotation2::from_angle(Angle::from_degrees(90.0)), vector!(1.0, 0.0));
+/// let point = pose.transform_point(&vector!(1.0, 0.0));
+///
+/// assert!(point.approx_eq(&vector!(1.0, 1.0), 1e-12));
+/// assert!(pose.inverse().transform_point(&point).approx_eq(&vector!(1.0, 0.0), 1e-12));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Isometry<T, const N: usize> {
+    rotation: Matrix<T, N, N>,
+    translation: Vector<T, N>,
+}
+
+/// Alias for a 2-dimensional isometry with the given type.
+pub type Isometry2<T> = Isometry<T, 2>;
+/// Alias for a 3-dimensional isometry with the given type.
+pub type Isometry3<T> = Isometry<T, 3>;
+
+impl<T: Real, const N: usize> Isometry<T, N> {
+    /// Creates a new transform that leaves every point unchanged.
+    pub fn identity() -> Self {
+        Self::from_translation(Vector::zero())
+    }
+
+    /// Creates a new transform that only translates.
+    pub fn from_translation(translation: Vector<T, N>) -> Self {
+        Self {
+            rotation: Matrix::identity(),
+            translation,
+        }
+    }
+
+    /// Returns the translation applied after the rotation.
+    pub fn translation(&self) -> Vector<T, N> {
+        self.translation
+    }
+
+    /// Returns the rotation as an orthonormal matrix.
+    pub fn rotation_matrix(&self) -> Matrix<T, N, N> {
+        self.rotation
+    }
+
+    /// Transforms a point, applying both the rotation and the translation.
+    pub fn transform_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.rotation * *point + self.translation
+    }
+
+    /// Transforms a direction, applying only the rotation.
+    pub fn transform_vector(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.rotation * *vector
+    }
+
+    /// Calculates the transform that undoes this one.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        Self {
+            rotation,
+            translation: Vector::zero() - rotation * self.translation,
+        }
+    }
+
+    /// Combines two transforms into one that applies `other` first and then this transform.
+    /// This is the same as `self * other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation * other.rotation,
+            translation: self.transform_point(&other.translation),
+        }
+    }
+
+    /// Converts the isometry into a general affine transform.
+    pub fn to_affine(&self) -> Affine<T, N> {
+        Affine::new(self.rotation, self.translation)
+    }
+}
+
+impl<T: Real> Isometry<T, 2> {
+    /// Creates a new transform from a rotation and the translation applied after it.
+    pub fn new(rotation: Rotation2<T>, translation: Vector<T, 2>) -> Self {
+        Self {
+            rotation: rotation.matrix(),
+            translation,
+        }
+    }
+
+    /// Creates the pose of an object at `eye` with its positive x axis facing `target`.
+    /// See [`Rotation2::look_at`].
+    pub fn look_at(eye: &Vector<T, 2>, target: &Vector<T, 2>) -> Self {
+        Self::new(Rotation2::look_at(eye, target), *eye)
+    }
+
+    /// Returns the rotation applied before the translation.
+    pub fn rotation(&self) -> Rotation2<T> {
+        Rotation2 {
+            sin: self.rotation.rows[1][0],
+            cos: self.rotation.rows[0][0],
+        }
+    }
+}
+
+impl<T: Real> Isometry<T, 3> {
+    /// Creates a new transform from a rotation and the translation applied after it.
+    pub fn new(rotation: Rotation3<T>, translation: Vector<T, 3>) -> Self {
+        Self {
+            rotation: rotation.matrix(),
+            translation,
+        }
+    }
+
+    /// Creates the pose of a camera at `eye` with its negative z axis facing `target` and its positive y axis as close to `up` as possible.
+    /// Its [inverse](Isometry::inverse) is the view transform, which moves world space points into the camera's space.
+    /// Returns None if the points are equal or the view direction is parallel to `up`.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Isometry3, vector};
+    /// let camera = Isometry3::look_at(&vector!(0.0, 0.0, 5.0), &vector!(0.0, 0.0, 0.0), &vector!(0.0, 1.0, 0.0)).unwrap();
+    /// let view = camera.inverse();
+    /// assert!(view.transform_point(&vector!(0.0, 0.0, 0.0)).approx_eq(&vector!(0.0, 0.0, -5.0), 1e-12));
+    /// ```
+    pub fn look_at(eye: &Vector<T, 3>, target: &Vector<T, 3>, up: &Vector<T, 3>) -> Option<Self> {
+        Some(Self::new(Rotation3::look_at(eye, target, up)?, *eye))
+    }
+
+    /// Returns the rotation applied before the translation.
+    pub fn rotation(&self) -> Rotation3<T> {
+        Rotation3 {
+            matrix: self.rotation,
+        }
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Isometry<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Isometry")
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl<T: Real, const N: usize> Mul for Isometry<T, N> {
+    type Output = Self;
+
+    /// Combines two transforms into one that applies `other` first and then this transform.
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+
+use num_traits::Num;
+
+use crate::Vector;
+
+/// A static [k-d tree](https://en.wikipedia.org/wiki/K-d_tree) for fast spatial queries over a set of points, each with an associated value.
+/// The tree is stored as a single sorted list, where the median of each sub-slice is the splitting node.
+///
+/// Distances are compared as squared Euclidean distances, so both integer and floating point components are supported.
+///
+/// ```rust
+/// # use nd_vec::{KdTree, vector};
+/// let tree = KdTree::new([(vector!(0, 0), "origin"), (vector!(5, 5), "far"), (vector!(1, 2), "near")]);
+/// assert_eq!(tree.nearest(&vector!(1, 1)).map(|x| *x.1), Some("near"));
+/// ```
+#[derive(Clone)]
+pub struct KdTree<T, const N: usize, V> {
+    nodes: Vec<(Vector<T, N>, V)>,
+}
+
+/// Squared Euclidean distance between two points, without requiring signed components.
+pub(crate) fn distance_squared<T: Num + Copy + PartialOrd, const N: usize>(
+    a: &Vector<T, N>,
+    b: &Vector<T, N>,
+) -> T {
+    let mut out = T::zero();
+    for (&a, &b) in a.components.iter().zip(b.components.iter()) {
+        let diff = if a > b { a - b } else { b - a };
+        out = out + diff * diff;
+    }
+    out
+}
+
+fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+impl<T: Num + Copy + PartialOrd, const N: usize, V> KdTree<T, N, V> {
+    /// Builds a new tree from a list of points and their values.
+    pub fn new(items: impl IntoIterator<Item = (Vector<T, N>, V)>) -> Self {
+        let mut nodes = items.into_iter().collect::<Vec<_>>();
+        Self::build(&mut nodes, 0);
+        Self { nodes }
+    }
+
+    fn build(nodes: &mut [(Vector<T, N>, V)], depth: usize) {
+        if nodes.len() <= 1 || N == 0 {
+            return;
+        }
+
+        let axis = depth % N;
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by(mid, |a, b| {
+            compare(&a.0.components[axis], &b.0.components[axis])
+        });
+
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    /// Returns the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the tree contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Iterates over all points and values in the tree, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Vector<T, N>, &V)> {
+        self.nodes.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Finds the point closest to `target`.
+    /// Returns None if the tree is empty.
+    pub fn nearest(&self, target: &Vector<T, N>) -> Option<(&Vector<T, N>, &V)> {
+        self.k_nearest(target, 1).into_iter().next()
+    }
+
+    /// Finds the `k` points closest to `target`, sorted from nearest to farthest.
+    /// If the tree has fewer than `k` points, all of them are returned.
+    pub fn k_nearest(&self, target: &Vector<T, N>, k: usize) -> Vec<(&Vector<T, N>, &V)> {
+        // Zero-dimensional points are all in the same place, and have no axis to split on
+        if N == 0 {
+            return self.iter().take(k).collect();
+        }
+
+        let mut best = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.k_nearest_in(0, self.nodes.len(), 0, target, k, &mut best);
+        }
+
+        best.into_iter()
+            .map(|(_, i)| {
+                let (key, value) = &self.nodes[i];
+                (key, value)
+            })
+            .collect()
+    }
+
+    fn k_nearest_in(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        target: &Vector<T, N>,
+        k: usize,
+        best: &mut Vec<(T, usize)>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let point = &self.nodes[mid].0;
+        let distance = distance_squared(point, target);
+        if best.len() < k || distance < best[best.len() - 1].0 {
+            let index = best.partition_point(|(d, _)| *d <= distance);
+            best.insert(index, (distance, mid));
+            best.truncate(k);
+        }
+
+        let axis = depth % N;
+        let (a, b) = (target.components[axis], point.components[axis]);
+        let ((near_start, near_end), (far_start, far_end)) = if a < b {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+
+        self.k_nearest_in(near_start, near_end, depth + 1, target, k, best);
+        let diff = if a > b { a - b } else { b - a };
+        if best.len() < k || diff * diff < best[best.len() - 1].0 {
+            self.k_nearest_in(far_start, far_end, depth + 1, target, k, best);
+        }
+    }
+
+    /// Finds all points within `radius` (inclusive) of `target`, in no particular order.
+    pub fn within_radius(&self, target: &Vector<T, N>, radius: T) -> Vec<(&Vector<T, N>, &V)> {
+        let mut out = Vec::new();
+        let radius_squared = radius * radius;
+        self.search(
+            0,
+            self.nodes.len(),
+            0,
+            &mut out,
+            &|point| distance_squared(point, target) <= radius_squared,
+            &|axis, split| {
+                let (a, b) = (target.components[axis], split);
+                let diff = if a > b { a - b } else { b - a };
+                (
+                    a <= b || diff * diff <= radius_squared,
+                    a >= b || diff * diff <= radius_squared,
+                )
+            },
+        );
+        out
+    }
+
+    /// Finds all points inside the axis-aligned box between `min` and `max` (inclusive), in no particular order.
+    pub fn within_box(&self, min: &Vector<T, N>, max: &Vector<T, N>) -> Vec<(&Vector<T, N>, &V)> {
+        let mut out = Vec::new();
+        self.search(
+            0,
+            self.nodes.len(),
+            0,
+            &mut out,
+            &|point| {
+                (0..N).all(|i| {
+                    min.components[i] <= point.components[i]
+                        && point.components[i] <= max.components[i]
+                })
+            },
+            &|axis, split| (min.components[axis] <= split, max.components[axis] >= split),
+        );
+        out
+    }
+
+    /// Collects every point matching `contains`.
+    /// `visit` is given the splitting axis and value of a node, and returns whether the lower and upper halves may contain matches.
+    fn search<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        out: &mut Vec<(&'a Vector<T, N>, &'a V)>,
+        contains: &impl Fn(&Vector<T, N>) -> bool,
+        visit: &impl Fn(usize, T) -> (bool, bool),
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let (point, value) = &self.nodes[mid];
+        if contains(point) {
+            out.push((point, value));
+        }
+
+        // Zero-dimensional trees aren't split on any axis, so both halves are searched
+        let (lower, upper) = match N {
+            0 => (true, true),
+            _ => {
+                let axis = depth % N;
+                visit(axis, point.components[axis])
+            }
+        };
+        if lower {
+            self.search(start, mid, depth + 1, out, contains, visit);
+        }
+        if upper {
+            self.search(mid + 1, end, depth + 1, out, contains, visit);
+        }
+    }
+}
+
+impl<T: Num + Copy + PartialOrd, const N: usize, V> FromIterator<(Vector<T, N>, V)>
+    for KdTree<T, N, V>
+{
+    fn from_iter<I: IntoIterator<Item = (Vector<T, N>, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
@@ -1,11 +1,70 @@
 #![doc = include_str!("../README.md")]
 
+mod affine;
+mod aligned;
+mod angle;
+mod batch;
+mod bezier;
+#[cfg(feature = "defmt")]
+mod defmt_format;
+mod direction;
+#[cfg(feature = "strict")]
+mod dispatch;
+#[cfg(feature = "emath")]
+mod emath;
+mod error;
+#[cfg(feature = "euclid")]
+mod euclid_units;
+#[cfg(feature = "geo-types")]
+mod geo;
+pub mod geometry;
+#[cfg(feature = "half")]
+mod half_float;
+mod hilbert;
+mod isometry;
+mod kd_tree;
+mod matrix;
+mod morton;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+mod polyline;
+#[cfg(feature = "rand")]
+mod random;
+mod rotation;
+mod sequence;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod slice;
+mod spatial_hash;
+mod spline;
+#[cfg(feature = "strict")]
+mod strict;
 #[cfg(test)]
 mod test;
+mod triangle;
 mod vector;
+pub use affine::{Affine, Affine2, Affine3};
+pub use aligned::Aligned16;
+pub use angle::Angle;
+pub use batch::VectorBatch;
+pub use bezier::Bezier;
+pub use direction::{Direction2, Direction3};
+pub use error::WrongLength;
+pub use isometry::{Isometry, Isometry2, Isometry3};
+pub use kd_tree::KdTree;
+pub use matrix::{Mat2, Mat3, Mat4, Matrix};
+pub use polyline::Polyline;
+pub use rotation::{Rotation2, Rotation3};
+#[cfg(feature = "serde")]
+pub use serde_impl::named as serde_named;
+pub use spatial_hash::{SpatialHash, SpatialHashId};
+pub use spline::{Interpolation, Spline};
+pub use triangle::Triangle;
 pub use vector::Vector;
 
 /// Alias for a 2-dimensional vector with the given type.
 pub type Vec2<T> = Vector<T, 2>;
 /// Alias for a 3-dimensional vector with the given type.
 pub type Vec3<T> = Vector<T, 3>;
+/// Alias for a 4-dimensional vector with the given type.
+pub type Vec4<T> = Vector<T, 4>;
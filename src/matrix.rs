@@ -0,0 +1,406 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Index, IndexMut, Mul, Sub},
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::{Angle, Isometry3, Vector};
+
+/// A matrix with `R` rows and `C` columns, stored in row-major order.
+/// Matrices multiply column vectors from the left, so `matrix * vector` transforms the vector.
+///
+/// ```rust
+/// # use nd_vec::{Matrix, vector};
+/// let a = Matrix::new([[1, 2], [3, 4]]);
+/// assert_eq!(a * vector!(1, 1), vector!(3, 7));
+/// assert_eq!(a[(1, 0)], 3);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    pub(crate) rows: [[T; C]; R],
+}
+
+/// Alias for a 2x2 matrix with the given type.
+pub type Mat2<T> = Matrix<T, 2, 2>;
+/// Alias for a 3x3 matrix with the given type.
+pub type Mat3<T> = Matrix<T, 3, 3>;
+/// Alias for a 4x4 matrix with the given type.
+pub type Mat4<T> = Matrix<T, 4, 4>;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new matrix from a list of rows.
+    pub const fn new(rows: [[T; C]; R]) -> Self {
+        Self { rows }
+    }
+
+    /// Creates a new matrix from a list of row vectors.
+    pub fn from_rows(rows: [Vector<T, C>; R]) -> Self {
+        Self::new(rows.map(|x| x.components))
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new matrix from a list of column vectors.
+    pub fn from_columns(columns: [Vector<T, R>; C]) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| columns[j].components[i])
+        }))
+    }
+
+    /// Returns the row with the given index.
+    ///
+    /// # Panics
+    /// If `index` is not less than R.
+    pub fn row(&self, index: usize) -> Vector<T, C> {
+        Vector::new(self.rows[index])
+    }
+
+    /// Returns the column with the given index.
+    ///
+    /// # Panics
+    /// If `index` is not less than C.
+    pub fn column(&self, index: usize) -> Vector<T, R> {
+        Vector::new(self.rows.map(|x| x[index]))
+    }
+
+    /// Swaps the rows and columns of a matrix.
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::new(std::array::from_fn(|i| self.column(i).components))
+    }
+}
+
+impl<T: Num + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new matrix with all elements set to zero.
+    pub fn zero() -> Self {
+        Self::new([[T::zero(); C]; R])
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Matrix<T, N, N> {
+    /// Creates a new identity matrix, with ones on the diagonal and zeros everywhere else.
+    pub fn identity() -> Self {
+        Self::from_diagonal(Vector::new([T::one(); N]))
+    }
+
+    /// Creates a new matrix with the given vector on the diagonal and zeros everywhere else.
+    /// This is the matrix that scales each axis by the matching component.
+    pub fn from_diagonal(diagonal: Vector<T, N>) -> Self {
+        let mut out = Self::zero();
+        for i in 0..N {
+            out.rows[i][i] = diagonal.components[i];
+        }
+        out
+    }
+
+    /// Returns the elements on the diagonal of a matrix.
+    pub fn diagonal(&self) -> Vector<T, N> {
+        Vector::new(std::array::from_fn(|i| self.rows[i][i]))
+    }
+
+    /// Calculates the sum of the elements on the diagonal.
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.rows[i][i])
+    }
+}
+
+impl<T: Real, const N: usize> Matrix<T, N, N> {
+    /// Calculates the determinant of a matrix with Gaussian elimination.
+    pub fn determinant(&self) -> T {
+        let mut rows = self.rows;
+        let mut out = T::one();
+
+        for i in 0..N {
+            let pivot = Self::pivot(&rows, i);
+            if rows[pivot][i] == T::zero() {
+                return T::zero();
+            }
+            if pivot != i {
+                rows.swap(pivot, i);
+                out = -out;
+            }
+
+            out = out * rows[i][i];
+            let pivot_row = rows[i];
+            for row in rows.iter_mut().skip(i + 1) {
+                let factor = row[i] / pivot_row[i];
+                for (e, &p) in row.iter_mut().zip(pivot_row.iter()).skip(i) {
+                    *e = *e - factor * p;
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Calculates the inverse of a matrix with Gauss-Jordan elimination.
+    /// Returns None if the matrix is singular.
+    ///
+    /// ```rust
+    /// # use nd_vec::Matrix;
+    /// let a = Matrix::new([[2.0, 0.0], [0.0, 4.0]]);
+    /// assert_eq!(a.inverse(), Some(Matrix::new([[0.5, 0.0], [0.0, 0.25]])));
+    /// assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let mut rows = self.rows;
+        let mut out = Self::identity().rows;
+
+        for i in 0..N {
+            let pivot = Self::pivot(&rows, i);
+            if rows[pivot][i] == T::zero() {
+                return None;
+            }
+            rows.swap(pivot, i);
+            out.swap(pivot, i);
+
+            let scale = rows[i][i];
+            rows[i] = rows[i].map(|x| x / scale);
+            out[i] = out[i].map(|x| x / scale);
+
+            let (pivot_row, pivot_out) = (rows[i], out[i]);
+            for j in (0..N).filter(|&j| j != i) {
+                let factor = rows[j][i];
+                for k in 0..N {
+                    rows[j][k] = rows[j][k] - factor * pivot_row[k];
+                    out[j][k] = out[j][k] - factor * pivot_out[k];
+                }
+            }
+        }
+
+        Some(Self::new(out))
+    }
+
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrix with the [Jacobi eigenvalue algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm).
+    /// Column `i` of the returned matrix is the unit eigenvector for eigenvalue `i`, and the eigenvectors are orthogonal to each other.
+    /// The eigenvalues are not sorted.
+    /// Only the upper triangle of the matrix is read, so the result is meaningless if the matrix is not symmetric.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Matrix, vector};
+    /// let (values, vectors) = Matrix::new([[2.0, 0.0], [0.0, 3.0]]).symmetric_eigen();
+    /// assert_eq!(values, vector!(2.0, 3.0));
+    /// assert_eq!(vectors, Matrix::identity());
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vector<T, N>, Self) {
+        let mut rows: [[T; N]; N] =
+            std::array::from_fn(|i| std::array::from_fn(|j| self.rows[i.min(j)][i.max(j)]));
+        let mut vectors = Self::identity().rows;
+
+        let norm = rows.iter().flatten().fold(T::zero(), |acc, &x| acc + x * x);
+        for _ in 0..64 {
+            let off_diagonal = rows
+                .iter()
+                .enumerate()
+                .flat_map(|(i, row)| &row[i + 1..])
+                .fold(T::zero(), |acc, &x| acc + x * x);
+            if off_diagonal <= norm * T::epsilon() * T::epsilon() {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if rows[p][q] == T::zero() {
+                        continue;
+                    }
+
+                    // Rotate in the p-q plane to zero out element (p, q)
+                    let theta = (rows[q][q] - rows[p][p]) / (rows[p][q] + rows[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let cos = T::one() / (t * t + T::one()).sqrt();
+                    let sin = t * cos;
+
+                    for row in rows.iter_mut().chain(vectors.iter_mut()) {
+                        let (a, b) = (row[p], row[q]);
+                        row[p] = cos * a - sin * b;
+                        row[q] = sin * a + cos * b;
+                    }
+                    let (a, b) = (rows[p], rows[q]);
+                    rows[p] = std::array::from_fn(|k| cos * a[k] - sin * b[k]);
+                    rows[q] = std::array::from_fn(|k| sin * a[k] + cos * b[k]);
+                }
+            }
+        }
+
+        (
+            Vector::new(std::array::from_fn(|i| rows[i][i])),
+            Self::new(vectors),
+        )
+    }
+
+    /// Finds the row at or below `column` with the largest absolute value in that column, for partial pivoting.
+    fn pivot(rows: &[[T; N]; N], column: usize) -> usize {
+        (column..N)
+            .max_by(|&a, &b| {
+                let (a, b) = (rows[a][column].abs(), rows[b][column].abs());
+                a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap()
+    }
+}
+
+impl<T: Real> Matrix<T, 4, 4> {
+    /// Creates a perspective projection matrix for a right-handed camera looking down the negative z axis, like OpenGL's `gluPerspective`.
+    /// `fov_y` is the vertical field of view, `aspect` is the width divided by the height, and `near` and `far` are the positive distances to the clipping planes.
+    /// Visible points are mapped into normalized device coordinates between -1 and 1 on every axis.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Angle, Matrix, vector};
+    /// let projection = Matrix::perspective(Angle::from_degrees(90.0), 1.0, 1.0, 10.0);
+    /// let ndc = projection.project_to_ndc(&vector!(1.0, 0.0, -1.0));
+    /// assert!(ndc.approx_eq(&vector!(1.0, 0.0, -1.0), 1e-12));
+    /// ```
+    pub fn perspective(fov_y: Angle<T>, aspect: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let f = T::one() / (fov_y / two).tan();
+        let depth = near - far;
+
+        let mut out = Self::zero();
+        out.rows[0][0] = f / aspect;
+        out.rows[1][1] = f;
+        out.rows[2][2] = (far + near) / depth;
+        out.rows[2][3] = two * far * near / depth;
+        out.rows[3][2] = -T::one();
+        out
+    }
+
+    /// Creates an orthographic projection matrix for a right-handed camera looking down the negative z axis, like OpenGL's `glOrtho`.
+    /// The box between the given planes is mapped into normalized device coordinates between -1 and 1 on every axis.
+    /// `near` and `far` are distances along the viewing direction, so points at `z = -near` map to -1.
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+
+        let mut out = Self::identity();
+        out.rows[0][0] = two / width;
+        out.rows[1][1] = two / height;
+        out.rows[2][2] = -two / depth;
+        out.rows[0][3] = -(right + left) / width;
+        out.rows[1][3] = -(top + bottom) / height;
+        out.rows[2][3] = -(far + near) / depth;
+        out
+    }
+
+    /// Creates a view matrix for a right-handed camera at `eye` looking towards `target`, like `gluLookAt`.
+    /// This moves world space points into the camera's space, where it looks down the negative z axis with the positive y axis as close to `up` as possible, ready for [`Matrix::perspective`].
+    /// Returns None if the points are equal or the view direction is parallel to `up`.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Matrix, vector};
+    /// let view = Matrix::look_at(&vector!(0.0, 0.0, 5.0), &vector!(0.0, 0.0, 0.0), &vector!(0.0, 1.0, 0.0)).unwrap();
+    /// let point = view * vector!(1.0, 0.0, 0.0, 1.0);
+    /// assert!(point.approx_eq(&vector!(1.0, 0.0, -5.0, 1.0), 1e-12));
+    /// ```
+    pub fn look_at(eye: &Vector<T, 3>, target: &Vector<T, 3>, up: &Vector<T, 3>) -> Option<Self> {
+        let view = Isometry3::look_at(eye, target, up)?.inverse();
+        let (rotation, translation) = (view.rotation_matrix(), view.translation());
+
+        let mut out = Self::identity();
+        for i in 0..3 {
+            out.rows[i][..3].copy_from_slice(&rotation.rows[i]);
+            out.rows[i][3] = translation.components[i];
+        }
+        Some(out)
+    }
+
+    /// Transforms a point by a projection matrix and applies the perspective divide, giving its normalized device coordinates.
+    pub fn project_to_ndc(&self, point: &Vector<T, 3>) -> Vector<T, 3> {
+        let [x, y, z] = point.components;
+        let [x, y, z, w] = (*self * Vector::new([x, y, z, T::one()])).components;
+        Vector::new([x / w, y / w, z / w])
+    }
+}
+
+impl<T: Display, const R: usize, const C: usize> Debug for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|x| {
+                let row = x.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                format!("({})", row.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        f.write_fmt(format_args!("[{}]", rows))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    /// Returns the element at the given row and column.
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.rows[row][column]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.rows[row][column]
+    }
+}
+
+macro_rules! matrix_op {
+    ($trait:tt, $func:ident) => {
+        impl<T: Num + Copy, const R: usize, const C: usize> $trait for Matrix<T, R, C> {
+            type Output = Self;
+
+            #[track_caller]
+            fn $func(self, other: Self) -> Self::Output {
+                Self::new(std::array::from_fn(|i| {
+                    std::array::from_fn(|j| self.rows[i][j].$func(other.rows[i][j]))
+                }))
+            }
+        }
+    };
+}
+
+matrix_op!(Add, add);
+matrix_op!(Sub, sub);
+
+impl<T: Num + Copy, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    #[track_caller]
+    fn mul(self, other: T) -> Self::Output {
+        Self::new(self.rows.map(|x| x.map(|x| x * other)))
+    }
+}
+
+impl<T: Num + Copy, const R: usize, const C: usize> Mul<Vector<T, C>> for Matrix<T, R, C> {
+    type Output = Vector<T, R>;
+
+    /// Transforms a column vector by this matrix.
+    #[track_caller]
+    fn mul(self, other: Vector<T, C>) -> Self::Output {
+        Vector::new(self.rows.map(|row| {
+            let mut out = T::zero();
+            for (&a, &b) in row.iter().zip(other.components.iter()) {
+                out = out + a * b;
+            }
+            out
+        }))
+    }
+}
+
+impl<T: Num + Copy, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>>
+    for Matrix<T, R, C>
+{
+    type Output = Matrix<T, R, K>;
+
+    /// Multiplies two matrices, giving the transform that applies `other` first and then this matrix.
+    #[track_caller]
+    fn mul(self, other: Matrix<T, C, K>) -> Self::Output {
+        let columns: [Vector<T, C>; K] = std::array::from_fn(|j| other.column(j));
+        Matrix::new(self.rows.map(|row| {
+            columns.map(|column| {
+                let mut out = T::zero();
+                for (&a, &b) in row.iter().zip(column.components.iter()) {
+                    out = out + a * b;
+                }
+                out
+            })
+        }))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Vector;
+
+macro_rules! morton_2d {
+    ($type:ty, $key:ty) => {
+        impl Vector<$type, 2> {
+            /// Encodes the vector as a [Morton code](https://en.wikipedia.org/wiki/Z-order_curve) by interleaving the bits of its components.
+            /// The bits of the x component occupy the even bit positions of the key.
+            pub fn to_morton(&self) -> $key {
+                let mut key = 0;
+                for i in 0..<$type>::BITS {
+                    key |= ((self.x() as $key >> i) & 1) << (2 * i);
+                    key |= ((self.y() as $key >> i) & 1) << (2 * i + 1);
+                }
+                key
+            }
+
+            /// Decodes a vector from a [Morton code](https://en.wikipedia.org/wiki/Z-order_curve).
+            /// This is the inverse of [`Vector::to_morton`].
+            pub fn from_morton(key: $key) -> Self {
+                let (mut x, mut y) = (0, 0);
+                for i in 0..<$type>::BITS {
+                    x |= ((key >> (2 * i)) & 1) << i;
+                    y |= ((key >> (2 * i + 1)) & 1) << i;
+                }
+                Self::new([x as $type, y as $type])
+            }
+        }
+    };
+}
+
+macro_rules! morton_3d {
+    ($type:ty, $key:ty) => {
+        impl Vector<$type, 3> {
+            /// Encodes the vector as a [Morton code](https://en.wikipedia.org/wiki/Z-order_curve) by interleaving the bits of its components.
+            /// Bit `i` of the x, y and z components is stored at bit `3i`, `3i + 1` and `3i + 2` of the key respectively.
+            pub fn to_morton(&self) -> $key {
+                let mut key = 0;
+                for i in 0..<$type>::BITS {
+                    key |= ((self.x() as $key >> i) & 1) << (3 * i);
+                    key |= ((self.y() as $key >> i) & 1) << (3 * i + 1);
+                    key |= ((self.z() as $key >> i) & 1) << (3 * i + 2);
+                }
+                key
+            }
+
+            /// Decodes a vector from a [Morton code](https://en.wikipedia.org/wiki/Z-order_curve).
+            /// This is the inverse of [`Vector::to_morton`].
+            pub fn from_morton(key: $key) -> Self {
+                let (mut x, mut y, mut z) = (0, 0, 0);
+                for i in 0..<$type>::BITS {
+                    x |= ((key >> (3 * i)) & 1) << i;
+                    y |= ((key >> (3 * i + 1)) & 1) << i;
+                    z |= ((key >> (3 * i + 2)) & 1) << i;
+                }
+                Self::new([x as $type, y as $type, z as $type])
+            }
+        }
+    };
+}
+
+morton_2d!(u8, u16);
+morton_2d!(u16, u32);
+morton_2d!(u32, u64);
+morton_2d!(u64, u128);
+
+morton_3d!(u8, u32);
+morton_3d!(u16, u64);
+morton_3d!(u32, u128);
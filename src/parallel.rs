@@ -0,0 +1,53 @@
+//! Parallel bulk operations over slices of vectors, powered by [rayon](https://docs.rs/rayon).
+
+use std::iter::Sum;
+
+use num_traits::{real::Real, Num, NumCast};
+use rayon::prelude::*;
+
+use crate::Vector;
+
+/// Applies the given function to every vector in the slice in parallel, replacing each vector with the result.
+///
+/// ```rust
+/// # use nd_vec::{parallel::par_map_slice, vector};
+/// let mut points = [vector!(1, 2), vector!(3, 4)];
+/// par_map_slice(&mut points, |x| x * 2);
+/// assert_eq!(points, [vector!(2, 4), vector!(6, 8)]);
+/// ```
+pub fn par_map_slice<T, const N: usize, F>(vectors: &mut [Vector<T, N>], f: F)
+where
+    T: Copy + Send,
+    F: Fn(Vector<T, N>) -> Vector<T, N> + Send + Sync,
+{
+    vectors.par_iter_mut().for_each(|x| *x = f(*x));
+}
+
+/// Calculates the centroid (mean) of all vectors in the slice in parallel.
+/// Returns None if the slice is empty or its length can't be represented as `T`.
+pub fn par_centroid<T, const N: usize>(vectors: &[Vector<T, N>]) -> Option<Vector<T, N>>
+where
+    T: Num + Copy + NumCast + Send + Sync,
+{
+    if vectors.is_empty() {
+        return None;
+    }
+
+    let count = T::from(vectors.len())?;
+    let sum = vectors
+        .par_iter()
+        .copied()
+        .reduce(Vector::zero, |a, b| a + b);
+    Some(sum / count)
+}
+
+/// Calculates the [Euclidean Distance](https://en.wikipedia.org/wiki/Euclidean_distance) between every vector in `a` and every vector in `b` in parallel.
+/// The result is in row-major order, so the distance between `a[i]` and `b[j]` is at index `i * b.len() + j`.
+pub fn par_pairwise_distances<T, const N: usize>(a: &[Vector<T, N>], b: &[Vector<T, N>]) -> Vec<T>
+where
+    T: Num + Copy + Sum + Real + Send + Sync,
+{
+    a.par_iter()
+        .flat_map_iter(|x| b.iter().map(move |y| x.distance(y)))
+        .collect()
+}
@@ -0,0 +1,138 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::Vector;
+
+/// A path made of straight line segments between a sequence of points, such as a GPS track.
+///
+/// ```rust
+/// # use nd_vec::{Polyline, vector};
+/// let path = Polyline::new(vec![vector!(0.0, 0.0), vector!(3.0, 0.0), vector!(3.0, 4.0)]);
+/// assert_eq!(path.length(), 7.0);
+/// assert_eq!(path.point_at_distance(5.0), vector!(3.0, 2.0));
+/// assert_eq!(path.closest_point(&vector!(1.0, 2.0)), vector!(1.0, 0.0));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Polyline<T, const N: usize> {
+    points: Vec<Vector<T, N>>,
+}
+
+impl<T, const N: usize> Polyline<T, N> {
+    /// Creates a new polyline through the given points.
+    ///
+    /// # Panics
+    /// If no points are given.
+    pub fn new(points: Vec<Vector<T, N>>) -> Self {
+        assert!(!points.is_empty(), "a polyline needs at least one point");
+        Self { points }
+    }
+
+    /// Returns the points of the polyline.
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+
+    /// Consumes the polyline, returning its points.
+    pub fn into_points(self) -> Vec<Vector<T, N>> {
+        self.points
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> Polyline<T, N> {
+    /// Calculates the total length of the polyline, the sum of the lengths of its segments.
+    pub fn length(&self) -> T {
+        self.points
+            .windows(2)
+            .map(|x| x[0].distance(&x[1]))
+            .fold(T::zero(), |acc, x| acc + x)
+    }
+
+    /// Finds the point `distance` along the polyline from its first point.
+    /// The distance is clamped to the length of the polyline, so negative values give the first point and values past the end give the last.
+    pub fn point_at_distance(&self, distance: T) -> Vector<T, N> {
+        let mut remaining = distance;
+        for segment in self.points.windows(2) {
+            let length = segment[0].distance(&segment[1]);
+            if remaining <= length {
+                if length <= T::zero() || remaining <= T::zero() {
+                    return segment[0];
+                }
+                return segment[0].lerp(&segment[1], remaining / length);
+            }
+            remaining = remaining - length;
+        }
+        self.points[self.points.len() - 1]
+    }
+
+    /// Finds the point on the polyline that is closest to `point`.
+    /// If several points are equally close, the one nearest the start of the polyline is returned.
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.closest(point).0
+    }
+
+    /// Finds the distance along the polyline, from its first point, of the point closest to `point`.
+    /// This is the inverse of [`Polyline::point_at_distance`], and is useful for tracking progress along a path.
+    pub fn project(&self, point: &Vector<T, N>) -> T {
+        self.closest(point).1
+    }
+
+    /// Resamples the polyline into `count` points spaced evenly along its length, keeping the first and last points.
+    /// A count of one gives just the first point.
+    ///
+    /// # Panics
+    /// If `count` is zero.
+    pub fn resample(&self, count: usize) -> Self {
+        assert!(count > 0, "a polyline needs at least one point");
+        if count == 1 {
+            return Self::new(vec![self.points[0]]);
+        }
+
+        let step = self.length() / T::from(count - 1).unwrap();
+        let mut points = (0..count - 1)
+            .map(|i| self.point_at_distance(step * T::from(i).unwrap()))
+            .collect::<Vec<_>>();
+        points.push(self.points[self.points.len() - 1]);
+        Self::new(points)
+    }
+
+    /// Finds the closest point on the polyline along with its distance along the polyline.
+    fn closest(&self, point: &Vector<T, N>) -> (Vector<T, N>, T) {
+        let mut best = (self.points[0], T::zero());
+        let mut best_distance = (*point - self.points[0]).magnitude_squared();
+        let mut traveled = T::zero();
+
+        for segment in self.points.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let direction = end - start;
+            let length_squared = direction.magnitude_squared();
+            let t = if length_squared > T::zero() {
+                ((*point - start).dot(&direction) / length_squared)
+                    .max(T::zero())
+                    .min(T::one())
+            } else {
+                T::zero()
+            };
+
+            let closest = start.lerp(&end, t);
+            let distance = (*point - closest).magnitude_squared();
+            let length = length_squared.sqrt();
+            if distance < best_distance {
+                best = (closest, traveled + length * t);
+                best_distance = distance;
+            }
+            traveled = traveled + length;
+        }
+
+        best
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Polyline<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Polyline").field(&self.points).finish()
+    }
+}
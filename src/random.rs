@@ -0,0 +1,60 @@
+//! Uniform random sampling of vectors, powered by [rand](https://docs.rs/rand).
+
+use num_traits::Float;
+use rand::{distr::uniform::SampleUniform, Rng, RngExt};
+
+use crate::Vector;
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Samples a point uniformly distributed on the surface of the unit sphere.
+    /// This works for any number of dimensions, by normalizing a vector of normally distributed components.
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        assert!(N > 0, "can't sample a unit vector with zero dimensions");
+        loop {
+            let out = Self::new(std::array::from_fn(|_| T::from(gaussian(rng)).unwrap()));
+            let magnitude = out
+                .components
+                .iter()
+                .fold(T::zero(), |acc, &x| acc + x * x)
+                .sqrt();
+
+            // Rejecting tiny vectors avoids dividing by (close to) zero.
+            if magnitude > T::epsilon() {
+                return out / magnitude;
+            }
+        }
+    }
+
+    /// Samples a point uniformly distributed inside the unit ball.
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn random_in_ball<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let radius = T::from(rng.random::<f64>().powf(1.0 / N as f64)).unwrap();
+        Self::new(Self::random_unit(rng).components.map(|x| x * radius))
+    }
+}
+
+impl<T: SampleUniform + PartialOrd + Copy, const N: usize> Vector<T, N> {
+    /// Samples a point uniformly distributed inside the axis-aligned box between `min` and `max` (inclusive).
+    ///
+    /// # Panics
+    /// If any component of `min` is greater than the matching component of `max`.
+    pub fn random_in_box<R: Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            let range = min.components[i]..=max.components[i];
+            assert!(!range.is_empty(), "min must not be greater than max");
+            rng.random_range(range)
+        }))
+    }
+}
+
+/// Samples a standard normally distributed value with the [Box-Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform).
+fn gaussian<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // `random` samples from [0, 1), so flip it to avoid taking the log of zero.
+    let (u, v) = (1.0 - rng.random::<f64>(), rng.random::<f64>());
+    (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+}
@@ -0,0 +1,261 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Mul,
+};
+
+use num_traits::real::Real;
+
+use crate::{Angle, Matrix, Vector};
+
+/// A rotation in 2D space, stored as the sine and cosine of its angle.
+/// Unlike a general [`Matrix`], this can only represent rotations, so it always preserves lengths and angles.
+///
+/// ```rust
+/// # use nd_vec::{Angle, Rotation2, vector};
+/// let rotation = Rotation2::from_angle(Angle::from_degrees(90.0));
+/// assert!(rotation.apply(&vector!(1.0, 0.0)).approx_eq(&vector!(0.0, 1.0), 1e-12));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rotation2<T> {
+    pub(crate) sin: T,
+    pub(crate) cos: T,
+}
+
+impl<T: Real> Rotation2<T> {
+    /// Creates a rotation that leaves every vector unchanged.
+    pub fn identity() -> Self {
+        Self {
+            sin: T::zero(),
+            cos: T::one(),
+        }
+    }
+
+    /// Creates a counterclockwise rotation by the given angle.
+    pub fn from_angle(angle: Angle<T>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { sin, cos }
+    }
+
+    /// Creates the rotation that turns the direction of `from` onto the direction of `to`.
+    /// Neither vector may be the zero vector.
+    pub fn between(from: &Vector<T, 2>, to: &Vector<T, 2>) -> Self {
+        Self::from_angle(from.signed_angle_to(to))
+    }
+
+    /// Creates the rotation that turns the positive x axis to face from `eye` towards `target`, such as the heading of a turret or a top-down character.
+    /// If the points are equal the rotation is the identity.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Angle, Rotation2, vector};
+    /// let heading = Rotation2::look_at(&vector!(1.0, 1.0), &vector!(1.0, 3.0));
+    /// assert_eq!(heading.angle(), Angle::from_degrees(90.0));
+    /// ```
+    pub fn look_at(eye: &Vector<T, 2>, target: &Vector<T, 2>) -> Self {
+        Self::between(&Vector::new([T::one(), T::zero()]), &(*target - *eye))
+    }
+
+    /// Returns the angle of the rotation, in the range (-π, π].
+    pub fn angle(&self) -> Angle<T> {
+        Angle::from_radians(self.sin.atan2(self.cos))
+    }
+
+    /// Returns the rotation as a 2x2 matrix.
+    pub fn matrix(&self) -> Matrix<T, 2, 2> {
+        Matrix::new([[self.cos, -self.sin], [self.sin, self.cos]])
+    }
+
+    /// Rotates a vector.
+    pub fn apply(&self, vector: &Vector<T, 2>) -> Vector<T, 2> {
+        let [x, y] = vector.components;
+        Vector::new([x * self.cos - y * self.sin, x * self.sin + y * self.cos])
+    }
+
+    /// Calculates the rotation in the opposite direction.
+    pub fn inverse(&self) -> Self {
+        Self {
+            sin: -self.sin,
+            cos: self.cos,
+        }
+    }
+
+    /// Combines two rotations into one that applies `other` first and then this rotation.
+    /// This is the same as `self * other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            sin: self.sin * other.cos + self.cos * other.sin,
+            cos: self.cos * other.cos - self.sin * other.sin,
+        }
+    }
+}
+
+/// A rotation in 3D space, stored as an orthonormal matrix.
+/// Unlike a general [`Matrix`], this can only represent rotations, so it always preserves lengths and angles.
+///
+/// ```rust
+/// # use nd_vec::{Angle, Rotation3, vector};
+/// let rotation = Rotation3::from_axis_angle(&vector!(0.0, 0.0, 1.0), Angle::from_degrees(90.0));
+/// assert!(rotation.apply(&vector!(1.0, 0.0, 0.0)).approx_eq(&vector!(0.0, 1.0, 0.0), 1e-12));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rotation3<T> {
+    pub(crate) matrix: Matrix<T, 3, 3>,
+}
+
+impl<T: Real> Rotation3<T> {
+    /// Creates a rotation that leaves every vector unchanged.
+    pub fn identity() -> Self {
+        Self {
+            matrix: Matrix::identity(),
+        }
+    }
+
+    /// Creates a rotation by `angle` around `axis`, counterclockwise when looking down the axis towards the origin.
+    /// The axis is normalized first, so it must not be the zero vector.
+    pub fn from_axis_angle(axis: &Vector<T, 3>, angle: Angle<T>) -> Self {
+        let length = axis
+            .components
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + x * x);
+        let [x, y, z] = axis.components.map(|e| e / length.sqrt());
+        let (sin, cos) = angle.sin_cos();
+        let t = T::one() - cos;
+
+        Self {
+            matrix: Matrix::new([
+                [t * x * x + cos, t * x * y - sin * z, t * x * z + sin * y],
+                [t * x * y + sin * z, t * y * y + cos, t * y * z - sin * x],
+                [t * x * z - sin * y, t * y * z + sin * x, t * z * z + cos],
+            ]),
+        }
+    }
+
+    /// Creates a rotation from an orthonormal matrix with a determinant of one.
+    /// Returns None if the matrix isn't a rotation, allowing `epsilon` of error in each element.
+    pub fn from_matrix(matrix: Matrix<T, 3, 3>, epsilon: T) -> Option<Self> {
+        let error = matrix * matrix.transpose() - Matrix::identity();
+        let orthonormal = error.rows.iter().flatten().all(|x| x.abs() <= epsilon);
+        let proper = (matrix.determinant() - T::one()).abs() <= epsilon;
+        (orthonormal && proper).then_some(Self { matrix })
+    }
+
+    /// Creates the rotation that turns the negative z axis to face from `eye` towards `target`, keeping the positive y axis as close to `up` as possible.
+    /// This is the orientation of a right-handed camera, as used by [`Matrix::perspective`].
+    /// Returns None if the points are equal or the view direction is parallel to `up`.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Rotation3, vector};
+    /// let up = vector!(0.0, 1.0, 0.0);
+    /// let camera = Rotation3::look_at(&vector!(0.0, 0.0, 0.0), &vector!(5.0, 0.0, 0.0), &up).unwrap();
+    /// assert!(camera.apply(&vector!(0.0, 0.0, -1.0)).approx_eq(&vector!(1.0, 0.0, 0.0), 1e-12));
+    /// assert!(camera.apply(&up).approx_eq(&up, 1e-12));
+    /// ```
+    pub fn look_at(eye: &Vector<T, 3>, target: &Vector<T, 3>, up: &Vector<T, 3>) -> Option<Self> {
+        let (zero, one) = (T::zero(), T::one());
+        Self::align_axes(
+            &Vector::new([zero, zero, -one]),
+            &Vector::new([zero, one, zero]),
+            &(*target - *eye),
+            up,
+        )
+    }
+
+    /// Creates the rotation that turns the local `forward` axis onto `direction`, keeping the local `local_up` axis as close to `up` as possible.
+    /// This is [`Rotation3::look_at`] for other conventions, such as a turret whose barrel points along the positive x axis.
+    /// Returns None if either pair of axes is parallel or contains a zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Rotation3, vector};
+    /// let (barrel, up) = (vector!(1.0, 0.0, 0.0), vector!(0.0, 0.0, 1.0));
+    /// let aim = Rotation3::align_axes(&barrel, &up, &vector!(0.0, 2.0, 2.0), &up).unwrap();
+    /// let expected = vector!(0.0, 1.0, 1.0).normalize();
+    /// assert!(aim.apply(&barrel).approx_eq(&expected, 1e-12));
+    /// ```
+    pub fn align_axes(
+        forward: &Vector<T, 3>,
+        local_up: &Vector<T, 3>,
+        direction: &Vector<T, 3>,
+        up: &Vector<T, 3>,
+    ) -> Option<Self> {
+        let local = frame(forward, local_up)?;
+        let world = frame(direction, up)?;
+        Some(Self {
+            matrix: world * local.transpose(),
+        })
+    }
+
+    /// Returns the rotation as a 3x3 matrix.
+    pub fn matrix(&self) -> Matrix<T, 3, 3> {
+        self.matrix
+    }
+
+    /// Rotates a vector.
+    pub fn apply(&self, vector: &Vector<T, 3>) -> Vector<T, 3> {
+        self.matrix * *vector
+    }
+
+    /// Calculates the rotation in the opposite direction.
+    /// For a rotation this is just the transpose of its matrix.
+    pub fn inverse(&self) -> Self {
+        Self {
+            matrix: self.matrix.transpose(),
+        }
+    }
+
+    /// Combines two rotations into one that applies `other` first and then this rotation.
+    /// This is the same as `self * other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            matrix: self.matrix * other.matrix,
+        }
+    }
+}
+
+/// Builds an orthonormal frame whose columns are `forward`, the part of `up` perpendicular to it, and the side axis, all normalized.
+fn frame<T: Real>(forward: &Vector<T, 3>, up: &Vector<T, 3>) -> Option<Matrix<T, 3, 3>> {
+    let forward = normalize(forward.components)?;
+    let side = normalize(cross(forward, up.components))?;
+    let up = cross(side, forward);
+    Some(Matrix::from_columns([forward, up, side].map(Vector::new)))
+}
+
+fn cross<T: Real>([ax, ay, az]: [T; 3], [bx, by, bz]: [T; 3]) -> [T; 3] {
+    [ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx]
+}
+
+fn normalize<T: Real>(vector: [T; 3]) -> Option<[T; 3]> {
+    let length = vector.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+    (length > T::zero()).then(|| vector.map(|x| x / length))
+}
+
+impl<T: Display> Debug for Rotation2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rotation2")
+            .field("sin", &format_args!("{}", self.sin))
+            .field("cos", &format_args!("{}", self.cos))
+            .finish()
+    }
+}
+
+impl<T: Display> Debug for Rotation3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Rotation3").field(&self.matrix).finish()
+    }
+}
+
+impl<T: Real> Mul for Rotation2<T> {
+    type Output = Self;
+
+    /// Combines two rotations into one that applies `other` first and then this rotation.
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
+
+impl<T: Real> Mul for Rotation3<T> {
+    type Output = Self;
+
+    /// Combines two rotations into one that applies `other` first and then this rotation.
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
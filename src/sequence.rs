@@ -0,0 +1,68 @@
+//! Low-discrepancy sequences, which cover the unit hypercube more evenly than random samples.
+//! Useful for quasi-Monte Carlo integration and well spread sample patterns.
+
+use num_traits::Float;
+
+use crate::Vector;
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Generates point `index` of the [Halton sequence](https://en.wikipedia.org/wiki/Halton_sequence), with every component in the range [0, 1).
+    /// Each axis uses the radical inverse in the next prime base, so the x axis uses base 2, the y axis base 3, and so on.
+    /// Index zero is always the origin, so sequences usually start from one.
+    ///
+    /// The Halton sequence starts to show correlation between axes in high dimensions, where [`Vector::r_sequence`] often works better.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// assert_eq!(Vector::<f64, 2>::halton(1), vector!(0.5, 1.0 / 3.0));
+    /// assert_eq!(Vector::<f64, 2>::halton(2), vector!(0.25, 2.0 / 3.0));
+    /// ```
+    pub fn halton(index: u64) -> Self {
+        let mut base = 1;
+        Self::new([(); N].map(|_| {
+            base = next_prime(base);
+            T::from(radical_inverse(index, base)).unwrap()
+        }))
+    }
+
+    /// Generates point `index` of Martin Roberts' [R sequence](https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/), with every component in the range [0, 1).
+    /// This is an additive recurrence based on a generalization of the golden ratio, which stays evenly spread in any number of dimensions and is cheaper to generate than [`Vector::halton`].
+    ///
+    /// ```rust
+    /// # use nd_vec::Vector;
+    /// let points = (0..100).map(Vector::<f64, 3>::r_sequence).collect::<Vec<_>>();
+    /// assert!(points.iter().all(|x| x.as_slice().iter().all(|e| (0.0..1.0).contains(e))));
+    /// ```
+    pub fn r_sequence(index: u64) -> Self {
+        // The generalized golden ratio is the positive root of x^(N + 1) = x + 1
+        let power = (N + 1) as i32;
+        let mut phi = 2.0f64;
+        for _ in 0..32 {
+            phi -= (phi.powi(power) - phi - 1.0) / (power as f64 * phi.powi(power - 1) - 1.0);
+        }
+
+        let mut alpha = 1.0;
+        Self::new([(); N].map(|_| {
+            alpha /= phi;
+            T::from((0.5 + alpha * index as f64).fract()).unwrap()
+        }))
+    }
+}
+
+/// Mirrors the digits of `index` in the given base around the radix point.
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let (mut out, mut scale) = (0.0, 1.0);
+    while index > 0 {
+        scale /= base as f64;
+        out += (index % base) as f64 * scale;
+        index /= base;
+    }
+    out
+}
+
+/// Finds the smallest prime greater than `n`.
+fn next_prime(n: u64) -> u64 {
+    (n + 1..)
+        .find(|&x| x >= 2 && (2..).take_while(|i| i * i <= x).all(|i| x % i != 0))
+        .unwrap()
+}
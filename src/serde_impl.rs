@@ -0,0 +1,136 @@
+//! [Serde](https://serde.rs) support for vectors.
+//! Vectors serialize as a fixed length sequence of their components by default, for example `[1, 2, 3]`.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::Vector;
+
+/// The component names used by the [`named`] representation.
+const FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+
+impl<T: Serialize, const N: usize> Serialize for Vector<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for component in &self.components {
+            tuple.serialize_element(component)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, VectorVisitor(PhantomData))
+    }
+}
+
+struct VectorVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for VectorVisitor<T, N> {
+    type Value = Vector<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a vector with {N} components")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut components = Vec::with_capacity(N);
+        while let Some(component) = seq.next_element()? {
+            if components.len() == N {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+            components.push(component);
+        }
+
+        let len = components.len();
+        let components = components
+            .try_into()
+            .map_err(|_| de::Error::invalid_length(len, &self))?;
+        Ok(Vector { components })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        if N > FIELDS.len() {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+
+        let fields = &FIELDS[..N];
+        let mut components = [(); N].map(|_| None);
+        while let Some(key) = map.next_key::<String>()? {
+            let index = fields
+                .iter()
+                .position(|&x| x == key)
+                .ok_or_else(|| de::Error::unknown_field(&key, fields))?;
+            if components[index].is_some() {
+                return Err(de::Error::duplicate_field(fields[index]));
+            }
+            components[index] = Some(map.next_value()?);
+        }
+
+        if let Some(index) = components.iter().position(Option::is_none) {
+            return Err(de::Error::missing_field(fields[index]));
+        }
+        Ok(Vector {
+            components: components.map(Option::unwrap),
+        })
+    }
+}
+
+/// An alternative serde representation that writes vectors as objects with named components, such as `{"x": 1, "y": 2}`.
+/// Only vectors with one to four components are supported, named `x`, `y`, `z`, and `w` in order.
+/// Use it on a field with `#[serde(with = "nd_vec::serde_named")]`.
+///
+/// ```rust
+/// # use nd_vec::{Vector, vector};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Particle {
+///     #[serde(with = "nd_vec::serde_named")]
+///     position: Vector<f32, 2>,
+///     velocity: Vector<f32, 2>,
+/// }
+///
+/// let particle = Particle { position: vector!(1.0, 2.0), velocity: vector!(0.5, 0.0) };
+/// let json = serde_json::to_string(&particle).unwrap();
+/// assert_eq!(json, r#"{"position":{"x":1.0,"y":2.0},"velocity":[0.5,0.0]}"#);
+/// ```
+pub mod named {
+    use super::*;
+
+    /// Serializes a vector as an object with named components.
+    /// Fails for vectors with more than four components.
+    pub fn serialize<S: Serializer, T: Serialize, const N: usize>(
+        vector: &Vector<T, N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if N > FIELDS.len() {
+            return Err(serde::ser::Error::custom(format!(
+                "can't name the components of a vector with {N} components"
+            )));
+        }
+
+        let mut object = serializer.serialize_struct("Vector", N)?;
+        for (name, component) in FIELDS.iter().zip(&vector.components) {
+            object.serialize_field(name, component)?;
+        }
+        object.end()
+    }
+
+    /// Deserializes a vector from an object with named components.
+    /// Sequences are accepted as well, for formats that write objects as sequences.
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Vector<T, N>, D::Error> {
+        deserializer.deserialize_struct(
+            "Vector",
+            &FIELDS[..N.min(FIELDS.len())],
+            VectorVisitor(PhantomData),
+        )
+    }
+}
@@ -0,0 +1,100 @@
+//! Batched operations over slices of vectors.
+//! These use a single fused loop over the inputs, which is easier for the compiler to vectorize than calling the per-vector methods in a loop.
+
+use num_traits::{real::Real, Num};
+
+use crate::Vector;
+
+/// Calculates the dot product of each pair of vectors from two slices.
+///
+/// # Panics
+/// If the slices have different lengths.
+///
+/// ```rust
+/// # use nd_vec::{slice::dot_slices, vector};
+/// let a = [vector!(1, 2), vector!(3, 4)];
+/// let b = [vector!(5, 6), vector!(7, 8)];
+/// assert_eq!(dot_slices(&a, &b), [17, 53]);
+/// ```
+pub fn dot_slices<T: Num + Copy, const N: usize>(a: &[Vector<T, N>], b: &[Vector<T, N>]) -> Vec<T> {
+    assert_eq!(a.len(), b.len(), "slice lengths must match");
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| {
+            let mut out = T::zero();
+            for i in 0..N {
+                out = out + a.components[i] * b.components[i];
+            }
+            out
+        })
+        .collect()
+}
+
+/// Calculates the [Euclidean Distance](https://en.wikipedia.org/wiki/Euclidean_distance) between each pair of vectors from two slices.
+///
+/// # Panics
+/// If the slices have different lengths.
+pub fn distances<T: Num + Copy + Real, const N: usize>(
+    a: &[Vector<T, N>],
+    b: &[Vector<T, N>],
+) -> Vec<T> {
+    assert_eq!(a.len(), b.len(), "slice lengths must match");
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| distance(a, b))
+        .collect()
+}
+
+/// Calculates the [Euclidean Distance](https://en.wikipedia.org/wiki/Euclidean_distance) between every vector in `a` and every vector in `b`.
+/// The result is in row-major order, so the distance between `a[i]` and `b[j]` is at index `i * b.len() + j`.
+pub fn pairwise_distances<T: Num + Copy + Real, const N: usize>(
+    a: &[Vector<T, N>],
+    b: &[Vector<T, N>],
+) -> Vec<T> {
+    let mut out = Vec::with_capacity(a.len() * b.len());
+    for x in a {
+        out.extend(b.iter().map(|y| distance(x, y)));
+    }
+    out
+}
+
+/// Transposes a slice of vectors into one list of values per component, converting from array-of-structures to structure-of-arrays layout.
+/// Lane `i` holds component `i` of every vector, in order.
+/// Use [`VectorBatch`](crate::VectorBatch) to keep working with the data in this layout.
+///
+/// ```rust
+/// # use nd_vec::{slice::{from_lanes, to_lanes}, vector};
+/// let points = [vector!(1, 2), vector!(3, 4), vector!(5, 6)];
+/// let [x, y] = to_lanes(&points);
+/// assert_eq!((x.as_slice(), y.as_slice()), (&[1, 3, 5][..], &[2, 4, 6][..]));
+/// assert_eq!(from_lanes([&x, &y]), points);
+/// ```
+pub fn to_lanes<T: Copy, const N: usize>(vectors: &[Vector<T, N>]) -> [Vec<T>; N] {
+    std::array::from_fn(|i| vectors.iter().map(|x| x.components[i]).collect())
+}
+
+/// Transposes one list of values per component back into a list of vectors, converting from structure-of-arrays to array-of-structures layout.
+/// This is the inverse of [`to_lanes`].
+///
+/// # Panics
+/// If the lanes have different lengths.
+pub fn from_lanes<T: Copy, const N: usize>(lanes: [&[T]; N]) -> Vec<Vector<T, N>> {
+    let len = lanes.first().map_or(0, |x| x.len());
+    assert!(
+        lanes.iter().all(|x| x.len() == len),
+        "lane lengths must match"
+    );
+    (0..len)
+        .map(|j| Vector::new(std::array::from_fn(|i| lanes[i][j])))
+        .collect()
+}
+
+#[inline(always)]
+fn distance<T: Num + Copy + Real, const N: usize>(a: &Vector<T, N>, b: &Vector<T, N>) -> T {
+    let mut out = T::zero();
+    for i in 0..N {
+        let diff = a.components[i] - b.components[i];
+        out = out + diff * diff;
+    }
+    out.sqrt()
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use num_traits::{Num, NumCast};
+
+use crate::{kd_tree::distance_squared, Vector};
+
+/// A spatial hash grid for fast neighborhood queries over moving points.
+/// Points are bucketed into cubic cells of a fixed size, keyed by their quantized position.
+///
+/// Positions are quantized through `f64`, so every method taking a position panics if a component can't be converted to `f64`.
+/// This can't happen for the primitive number types.
+///
+/// ```rust
+/// # use nd_vec::{SpatialHash, vector};
+/// let mut grid = SpatialHash::new(10.0);
+/// let a = grid.insert(vector!(1.0, 1.0), "a");
+/// grid.insert(vector!(25.0, 1.0), "b");
+///
+/// assert_eq!(grid.query_radius(&vector!(0.0, 0.0), 5.0).len(), 1);
+/// grid.remove(a);
+/// assert!(grid.query_radius(&vector!(0.0, 0.0), 5.0).is_empty());
+/// ```
+#[derive(Clone)]
+pub struct SpatialHash<T, const N: usize, V> {
+    cell_size: T,
+    cells: HashMap<Vector<i64, N>, Vec<usize>>,
+    entries: Vec<Slot<T, N, V>>,
+    free: Vec<usize>,
+}
+
+/// Identifies a point in a [`SpatialHash`].
+/// Removing a point invalidates its id, so a stale id can't refer to a point inserted later in its place.
+/// Ids only repeat once a single slot has been reused 2<sup>32</sup> times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpatialHashId {
+    index: usize,
+    generation: u32,
+}
+
+/// Storage for one point, reused after the point is removed.
+/// The generation is bumped on every removal to invalidate old ids.
+#[derive(Clone)]
+struct Slot<T, const N: usize, V> {
+    generation: u32,
+    entry: Option<(Vector<T, N>, V)>,
+}
+
+impl<T: Num + Copy + PartialOrd + NumCast, const N: usize, V> SpatialHash<T, N, V> {
+    /// Creates a new empty spatial hash with the given cell size.
+    /// For the best performance the cell size should be close to the typical query radius.
+    ///
+    /// # Panics
+    /// If the cell size is not positive.
+    pub fn new(cell_size: T) -> Self {
+        assert!(cell_size > T::zero(), "cell size must be positive");
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of points in the spatial hash.
+    pub fn len(&self) -> usize {
+        self.entries.len() - self.free.len()
+    }
+
+    /// Returns true if the spatial hash contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the key of the cell containing the given position.
+    /// Positions beyond the range of `i64` cells are clamped to the outermost cells.
+    ///
+    /// # Panics
+    /// If a component can't be converted to `f64`.
+    pub fn cell(&self, position: &Vector<T, N>) -> Vector<i64, N> {
+        Vector::new(self.scaled(position, 0.0).map(|x| x as i64))
+    }
+
+    /// Divides each component, moved by `offset`, by the cell size and rounds it down.
+    fn scaled(&self, position: &Vector<T, N>, offset: f64) -> [f64; N] {
+        let cell_size = to_f64(self.cell_size);
+        position
+            .components
+            .map(|x| ((to_f64(x) + offset) / cell_size).floor())
+    }
+
+    /// Inserts a point with an associated value.
+    /// Returns an id that can be used to get or remove the point later.
+    pub fn insert(&mut self, position: Vector<T, N>, value: V) -> SpatialHashId {
+        let cell = self.cell(&position);
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.entries[index].entry = Some((position, value));
+                index
+            }
+            None => {
+                self.entries.push(Slot {
+                    generation: 0,
+                    entry: Some((position, value)),
+                });
+                self.entries.len() - 1
+            }
+        };
+
+        self.cells.entry(cell).or_default().push(index);
+        SpatialHashId {
+            index,
+            generation: self.entries[index].generation,
+        }
+    }
+
+    /// Removes the point with the given id, returning its position and value.
+    /// Returns None if there is no point with that id, including if it was already removed.
+    pub fn remove(&mut self, id: SpatialHashId) -> Option<(Vector<T, N>, V)> {
+        let slot = self
+            .entries
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)?;
+        let (position, value) = slot.entry.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.index);
+
+        let cell = self.cell(&position);
+        if let Some(indices) = self.cells.get_mut(&cell) {
+            indices.retain(|&x| x != id.index);
+            if indices.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+
+        Some((position, value))
+    }
+
+    /// Returns the position and value of the point with the given id.
+    /// Returns None if the point has been removed.
+    pub fn get(&self, id: SpatialHashId) -> Option<(&Vector<T, N>, &V)> {
+        let slot = self
+            .entries
+            .get(id.index)
+            .filter(|slot| slot.generation == id.generation)?;
+        slot.entry
+            .as_ref()
+            .map(|(position, value)| (position, value))
+    }
+
+    /// Finds all points within `radius` (inclusive) of `center`, in no particular order.
+    /// Each result contains the id, position, and value of the point.
+    ///
+    /// Only the cells overlapping the query are visited, unless there are more of them than occupied cells, in which case every occupied cell is checked instead.
+    /// This bounds the cost of a huge or infinite radius by the number of points.
+    /// A negative or NaN radius finds nothing, and for integer types `radius * radius` must not overflow.
+    pub fn query_radius(
+        &self,
+        center: &Vector<T, N>,
+        radius: T,
+    ) -> Vec<(SpatialHashId, &Vector<T, N>, &V)> {
+        let mut out = Vec::new();
+        if radius < T::zero() {
+            return out;
+        }
+
+        let radius_squared = radius * radius;
+        let mut check = |index: usize| {
+            let slot = &self.entries[index];
+            let (position, value) = slot.entry.as_ref().unwrap();
+            if distance_squared(position, center) <= radius_squared {
+                let id = SpatialHashId {
+                    index,
+                    generation: slot.generation,
+                };
+                out.push((id, position, value));
+            }
+        };
+
+        let radius_f64 = to_f64(radius);
+        let (min, max) = (
+            self.scaled(center, -radius_f64),
+            self.scaled(center, radius_f64),
+        );
+        let box_cells = (0..N).map(|i| max[i] - min[i] + 1.0).product::<f64>();
+
+        if box_cells <= self.cells.len() as f64 {
+            let (min, max) = (min.map(|x| x as i64), max.map(|x| x as i64));
+            for cell in Vector::iter_box(Vector::new(min), Vector::new(max)) {
+                for &index in self.cells.get(&cell).into_iter().flatten() {
+                    check(index);
+                }
+            }
+        } else {
+            // Also reached for a NaN or infinite radius, which make the box size NaN or infinite
+            self.cells
+                .values()
+                .flatten()
+                .for_each(|&index| check(index));
+        }
+        out
+    }
+}
+
+/// Converts a coordinate to `f64` for quantizing it into cells.
+fn to_f64<T: NumCast>(x: T) -> f64 {
+    x.to_f64()
+        .expect("spatial hash coordinates must be convertible to f64")
+}
@@ -0,0 +1,170 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::Vector;
+
+/// The way a [`Spline`] interpolates between its keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Straight lines between keyframes.
+    Linear,
+    /// A smooth [uniform Catmull-Rom spline](https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline) that passes through every keyframe.
+    CatmullRom,
+    /// A uniform cubic [B-spline](https://en.wikipedia.org/wiki/B-spline), which is even smoother but only approximates the keyframes.
+    BSpline,
+}
+
+/// A curve through a sequence of keyframes, such as a camera path or an animation track.
+/// The curve is parameterized from `t = 0` at the first keyframe to `t = 1` at the last, with every segment between keyframes taking an equal share of the range.
+///
+/// ```rust
+/// # use nd_vec::{Interpolation, Spline, vector};
+/// let spline = Spline::new(vec![vector!(0.0, 0.0), vector!(1.0, 1.0), vector!(2.0, 0.0)], Interpolation::CatmullRom);
+/// assert_eq!(spline.sample(0.5), vector!(1.0, 1.0));
+/// assert_eq!(spline.sample(1.0), vector!(2.0, 0.0));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Spline<T, const N: usize> {
+    keyframes: Vec<Vector<T, N>>,
+    interpolation: Interpolation,
+}
+
+impl<T: Real, const N: usize> Spline<T, N> {
+    /// Creates a new spline through the given keyframes.
+    ///
+    /// # Panics
+    /// If no keyframes are given.
+    pub fn new(keyframes: Vec<Vector<T, N>>, interpolation: Interpolation) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "a spline needs at least one keyframe"
+        );
+        Self {
+            keyframes,
+            interpolation,
+        }
+    }
+
+    /// Returns the keyframes of the spline.
+    pub fn keyframes(&self) -> &[Vector<T, N>] {
+        &self.keyframes
+    }
+
+    /// Returns the way the spline interpolates between keyframes.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Samples the spline at `t`, which is clamped to the range [0, 1].
+    pub fn sample(&self, t: T) -> Vector<T, N> {
+        let segments = self.keyframes.len() - 1;
+        if segments == 0 {
+            return self.keyframes[0];
+        }
+
+        let position = t.max(T::zero()).min(T::one()) * T::from(segments).unwrap();
+        let index = position.floor().to_usize().unwrap().min(segments - 1);
+        let u = position - T::from(index).unwrap();
+
+        let point = |i: isize| {
+            let i = i.clamp(0, segments as isize) as usize;
+            self.keyframes[i]
+        };
+        let i = index as isize;
+        let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+
+        let (u2, u3) = (u * u, u * u * u);
+        let constant = |x: i8| T::from(x).unwrap();
+        let weights = match self.interpolation {
+            Interpolation::Linear => [T::zero(), T::one() - u, u, T::zero()],
+            Interpolation::CatmullRom => {
+                let half = T::from(0.5).unwrap();
+                [
+                    half * (-u3 + constant(2) * u2 - u),
+                    half * (constant(3) * u3 - constant(5) * u2 + constant(2)),
+                    half * (constant(-3) * u3 + constant(4) * u2 + u),
+                    half * (u3 - u2),
+                ]
+            }
+            Interpolation::BSpline => {
+                let sixth = T::one() / constant(6);
+                let inverse = T::one() - u;
+                [
+                    sixth * inverse * inverse * inverse,
+                    sixth * (constant(3) * u3 - constant(6) * u2 + constant(4)),
+                    sixth * (constant(-3) * u3 + constant(3) * u2 + constant(3) * u + T::one()),
+                    sixth * u3,
+                ]
+            }
+        };
+
+        let mut out = Vector::zero();
+        for (point, weight) in [p0, p1, p2, p3].iter().zip(weights) {
+            for (e, &x) in out.components.iter_mut().zip(point.components.iter()) {
+                *e = *e + x * weight;
+            }
+        }
+        out
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> Spline<T, N> {
+    /// Approximates the length of the spline by summing the lengths of `samples` straight lines between evenly spaced values of `t`.
+    pub fn length(&self, samples: usize) -> T {
+        self.distances(samples).last().copied().unwrap_or(T::zero())
+    }
+
+    /// Samples the spline at the point `distance` along its length, which is clamped to the length of the spline.
+    /// Unlike [`Spline::sample`], this moves at a constant speed along the curve.
+    /// The length is approximated with `samples` straight lines, so more samples give a more accurate result.
+    pub fn sample_at_distance(&self, distance: T, samples: usize) -> Vector<T, N> {
+        let distances = self.distances(samples);
+        let samples = T::from(distances.len() - 1).unwrap();
+
+        let index = distances.partition_point(|&x| x < distance);
+        if index == 0 {
+            return self.sample(T::zero());
+        }
+        if index == distances.len() {
+            return self.sample(T::one());
+        }
+
+        let (start, end) = (distances[index - 1], distances[index]);
+        let fraction = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            T::zero()
+        };
+        self.sample((T::from(index - 1).unwrap() + fraction) / samples)
+    }
+
+    /// Calculates the cumulative distance along the spline at `samples + 1` evenly spaced values of `t`.
+    fn distances(&self, samples: usize) -> Vec<T> {
+        let samples = samples.max(1);
+        let count = T::from(samples).unwrap();
+
+        let mut out = Vec::with_capacity(samples + 1);
+        let (mut previous, mut total) = (self.sample(T::zero()), T::zero());
+        out.push(total);
+        for i in 1..=samples {
+            let point = self.sample(T::from(i).unwrap() / count);
+            total = total + point.distance(&previous);
+            out.push(total);
+            previous = point;
+        }
+        out
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Spline<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Spline")
+            .field("keyframes", &self.keyframes)
+            .field("interpolation", &self.interpolation)
+            .finish()
+    }
+}
@@ -0,0 +1,100 @@
+//! Overflow checking for the arithmetic operators, enabled with the `strict` feature.
+//!
+//! With the feature, `+`, `-`, `*`, `/`, and `%` on integer vectors, their assigning versions, and the scalar forms use checked arithmetic for every component.
+//! When a component overflows or divides by zero they panic with both operands in every build profile, rather than with a bare "attempt to add with overflow" in debug builds and a silently wrapped result in release builds.
+//! The panic points at the caller's expression.
+//!
+//! ```rust
+//! # use nd_vec::vector;
+//! let result = std::panic::catch_unwind(|| vector!(250u8, 1) + vector!(10, 1));
+//! # #[cfg(feature = "strict")]
+//! assert_eq!(
+//!     *result.unwrap_err().downcast::<String>().unwrap(),
+//!     "vector addition overflowed: (250, 1) + (10, 1)"
+//! );
+//! ```
+//!
+//! The integer type is picked by comparing type ids, see [`crate::dispatch`], so the operators don't need any extra bounds.
+//! Other component types, such as floats, are unaffected.
+
+use std::fmt::{self, Debug};
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub};
+
+use crate::dispatch::{cast, is, Op};
+
+/// Combines each pair of components with `op`, panicking with both operands if any component overflows or divides by zero.
+/// Returns None if the component type isn't a primitive integer.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn zip<T: Copy, const N: usize>(a: &[T; N], b: &[T; N], op: Op) -> Option<[T; N]> {
+    macro_rules! integers {
+        ($($type:ty),*) => {
+            $(
+                if is::<T, $type>() {
+                    // SAFETY: `T` is `$type`.
+                    return Some(unsafe { cast(checked::<$type, N>(&cast(*a), &cast(*b), op)) });
+                }
+            )*
+        };
+    }
+
+    integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    None
+}
+
+#[inline(always)]
+#[track_caller]
+fn checked<T, const N: usize>(a: &[T; N], b: &[T; N], op: Op) -> [T; N]
+where
+    T: Copy + Debug + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+{
+    let mut out = *a;
+    for (x, y) in out.iter_mut().zip(b.iter()) {
+        let result = match op {
+            Op::Add => x.checked_add(y),
+            Op::Sub => x.checked_sub(y),
+            Op::Mul => x.checked_mul(y),
+            Op::Div => x.checked_div(y),
+            Op::Rem => x.checked_rem(y),
+        };
+        match result {
+            Some(e) => *x = e,
+            None => overflow(a, b, op),
+        }
+    }
+    out
+}
+
+#[cold]
+#[track_caller]
+fn overflow<T: Debug>(a: &[T], b: &[T], op: Op) -> ! {
+    let (problem, symbol) = match op {
+        Op::Add => ("addition overflowed", "+"),
+        Op::Sub => ("subtraction overflowed", "-"),
+        Op::Mul => ("multiplication overflowed", "*"),
+        Op::Div => ("division overflowed or divided by zero", "/"),
+        Op::Rem => ("remainder overflowed or divided by zero", "%"),
+    };
+    panic!(
+        "vector {problem}: {:?} {symbol} {:?}",
+        Components(a),
+        Components(b)
+    )
+}
+
+/// Formats components the same way as a vector's `Debug` implementation.
+struct Components<'a, T>(&'a [T]);
+
+impl<T: Debug> Debug for Components<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            e.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+}
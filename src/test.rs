@@ -62,3 +62,2077 @@ fn test_cast() {
     assert_eq!(a.num_cast().unwrap(), b);
     assert_eq!(b.cast(), a);
 }
+
+#[test]
+fn test_iter_box() {
+    let points = Vector::iter_box(vector!(0, 0, 0), vector!(1, 2, 3)).collect::<Vec<_>>();
+    assert_eq!(points.len(), 2 * 3 * 4);
+    assert_eq!(points[0], vector!(0, 0, 0));
+    assert_eq!(points[1], vector!(1, 0, 0));
+    assert_eq!(points[23], vector!(1, 2, 3));
+
+    assert_eq!(Vector::iter_box(vector!(1, 0), vector!(0, 1)).count(), 0);
+}
+
+#[test]
+fn test_morton() {
+    assert_eq!(vector!(0b11u32, 0b00).to_morton(), 0b0101);
+    assert_eq!(vector!(0b00u32, 0b11).to_morton(), 0b1010);
+    assert_eq!(vector!(1u16, 0, 1).to_morton(), 0b101);
+
+    let a = vector!(12345u32, 67890);
+    assert_eq!(Vector::<u32, 2>::from_morton(a.to_morton()), a);
+    let b = vector!(u32::MAX, 7, 1 << 31);
+    assert_eq!(Vector::<u32, 3>::from_morton(b.to_morton()), b);
+}
+
+#[test]
+fn test_hilbert() {
+    let order = [vector!(0u8, 0), vector!(0, 1), vector!(1, 1), vector!(1, 0)];
+    for (i, e) in order.iter().enumerate() {
+        assert_eq!(e.to_hilbert(1), i as u16);
+        assert_eq!(Vector::<u8, 2>::from_hilbert(i as u16, 1), *e);
+    }
+
+    // Consecutive indices must be neighbors on the grid
+    for i in 0..511u64 {
+        let a = Vector::<u16, 3>::from_hilbert(i, 3)
+            .num_cast::<i32>()
+            .unwrap();
+        let b = Vector::<u16, 3>::from_hilbert(i + 1, 3)
+            .num_cast::<i32>()
+            .unwrap();
+        assert_eq!(a.manhattan_distance(&b), 1);
+        assert_eq!(a.num_cast::<u16>().unwrap().to_hilbert(3), i);
+    }
+
+    let a = vector!(u64::MAX, 12345);
+    assert_eq!(Vector::<u64, 2>::from_hilbert(a.to_hilbert(64), 64), a);
+}
+
+#[test]
+fn test_direction() {
+    use crate::{Direction2, Direction3};
+
+    assert_eq!(Direction2::North.turn_right(), Direction2::East);
+    assert_eq!(Direction2::North.turn_left(), Direction2::West);
+    assert_eq!(Direction2::NorthEast.opposite(), Direction2::SouthWest);
+    assert_eq!(Direction2::SouthEast.as_vector::<i32>(), vector!(1, -1));
+
+    let mut pos = vector!(0, 0);
+    let mut dir = Direction2::North;
+    for _ in 0..4 {
+        pos += dir.into();
+        dir = dir.turn_right();
+    }
+    assert_eq!(pos, vector!(0, 0));
+
+    assert_eq!(Direction3::Up.turn_left(), Direction3::Up);
+    assert_eq!(Direction3::East.turn_left(), Direction3::North);
+    assert_eq!(Vector::from(Direction3::Down.opposite()), vector!(0, 0, 1));
+}
+
+#[test]
+fn test_wrapping_ops() {
+    let a = vector!(250u8, 1, 128);
+    let b = vector!(10u8, 2, 2);
+
+    assert_eq!(a.wrapping_add(&b), vector!(4, 3, 130));
+    assert_eq!(b.wrapping_sub(&a), vector!(16, 1, 130));
+    assert_eq!(a.wrapping_mul(&b), vector!(196, 2, 0));
+    assert_eq!(a.wrapping_add_scalar(6), vector!(0, 7, 134));
+    assert_eq!(a.wrapping_sub_scalar(2), vector!(248, 255, 126));
+    assert_eq!(a.wrapping_mul_scalar(2), vector!(244, 2, 0));
+}
+
+#[test]
+fn test_checked_ops() {
+    let a = vector!(110i8, -120, 5);
+    let b = vector!(20i8, 20, 2);
+
+    assert_eq!(a.checked_add(&b), None);
+    assert_eq!(a.checked_sub(&b), None);
+    assert_eq!(a.checked_mul(&b), None);
+    assert_eq!(a.checked_div(&b), Some(vector!(5, -6, 2)));
+    assert_eq!(a.checked_div(&vector!(1, 0, 1)), None);
+    assert_eq!(b.checked_add(&b), Some(vector!(40, 40, 4)));
+
+    assert_eq!(a.checked_div_scalar(0), None);
+    assert_eq!(a.checked_div_scalar(-1), Some(vector!(-110, 120, -5)));
+    assert_eq!(vector!(i8::MIN, 0).checked_div_scalar(-1), None);
+}
+
+#[test]
+fn test_saturating_ops() {
+    let a = vector!(250u8, 1, 128);
+    let b = vector!(10u8, 2, 2);
+
+    assert_eq!(a.saturating_add(&b), vector!(255, 3, 130));
+    assert_eq!(b.saturating_sub(&a), vector!(0, 1, 0));
+    assert_eq!(a.saturating_mul(&b), vector!(255, 2, 255));
+    assert_eq!(a.saturating_add_scalar(10), vector!(255, 11, 138));
+    assert_eq!(a.saturating_sub_scalar(2), vector!(248, 0, 126));
+    assert_eq!(
+        vector!(-100i8, 100).saturating_mul_scalar(2),
+        vector!(-128, 127)
+    );
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let a = vector!(250u8, 1, 128);
+    let b = vector!(10u8, 2, 2);
+
+    assert_eq!(
+        a.overflowing_add(&b),
+        (vector!(4, 3, 130), vector!(true, false, false))
+    );
+    assert_eq!(
+        b.overflowing_sub(&a),
+        (vector!(16, 1, 130), vector!(true, false, true))
+    );
+    assert_eq!(
+        a.overflowing_mul(&b),
+        (vector!(196, 2, 0), vector!(true, false, true))
+    );
+}
+
+#[test]
+fn test_euclid() {
+    let a = vector!(-17, 17, -16);
+
+    assert_eq!(a.div_euclid(&vector!(16, 16, 16)), vector!(-2, 1, -1));
+    assert_eq!(a.rem_euclid(&vector!(16, 16, 16)), vector!(15, 1, 0));
+    assert_eq!(a.div_euclid_scalar(-16), vector!(2, -1, 1));
+    assert_eq!(a.rem_euclid_scalar(-16), vector!(15, 1, 0));
+}
+
+#[test]
+fn test_reduced() {
+    assert_eq!(vector!(4, 6).reduced(), vector!(2, 3));
+    assert_eq!(vector!(-4, 6, 0).reduced(), vector!(-2, 3, 0));
+    assert_eq!(vector!(0, -5).reduced(), vector!(0, -1));
+    assert_eq!(vector!(0u32, 0).reduced(), vector!(0, 0));
+    assert_eq!(vector!(12u32, 18, 30).reduced(), vector!(2, 3, 5));
+    assert_eq!(vector!(i32::MIN, -1).reduced(), vector!(i32::MIN, -1));
+    assert_eq!(vector!(-1, i32::MIN).reduced(), vector!(-1, i32::MIN));
+    assert_eq!(vector!(i32::MIN, 0).reduced(), vector!(-1, 0));
+    assert_eq!(vector!(i8::MIN, i8::MIN).reduced(), vector!(-1, -1));
+    assert_eq!(vector!(i8::MIN, 64).reduced(), vector!(-2, 1));
+}
+
+#[test]
+fn test_rings() {
+    let center = vector!(3, -2, 1);
+    for radius in 0..4 {
+        let manhattan = center.manhattan_ring(radius).collect::<Vec<_>>();
+        let expected = Vector::iter_box(center - radius, center + radius)
+            .filter(|x| x.manhattan_distance(&center) == radius)
+            .count();
+        assert_eq!(manhattan.len(), expected);
+        assert!(manhattan
+            .iter()
+            .all(|x| x.manhattan_distance(&center) == radius));
+
+        let chebyshev = center.chebyshev_ring(radius).collect::<Vec<_>>();
+        let expected = Vector::iter_box(center - radius, center + radius)
+            .filter(|x| (*x - center).abs().max_component() == radius)
+            .count();
+        assert_eq!(chebyshev.len(), expected);
+        assert!(chebyshev
+            .iter()
+            .all(|x| (*x - center).abs().max_component() == radius));
+    }
+
+    assert_eq!(vector!(0, 0).manhattan_ring(-1).count(), 0);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_parallel() {
+    use crate::parallel::*;
+
+    let mut points = (0..1000).map(|x| vector!(x, -x)).collect::<Vec<_>>();
+    par_map_slice(&mut points, |x| x + 1);
+    assert_eq!(points[999], vector!(1000, -998));
+    assert_eq!(par_centroid(&points), Some(vector!(500, -498)));
+    assert_eq!(par_centroid::<i32, 2>(&[]), None);
+
+    let a = [vector!(0.0, 0.0), vector!(1.0, 1.0)];
+    let b = [vector!(3.0, 4.0), vector!(0.0, 0.0), vector!(1.0, 1.0)];
+    let distances = par_pairwise_distances(&a, &b);
+    assert_eq!(distances.len(), 6);
+    assert_eq!(distances[0], 5.0);
+    assert_eq!(distances[5], 0.0);
+}
+
+#[test]
+fn test_batch() {
+    use crate::VectorBatch;
+
+    let points = [vector!(3.0, 4.0), vector!(0.0, 2.0), vector!(1.0, 0.0)];
+    let batch = VectorBatch::from(points.as_slice());
+    assert_eq!(batch.len(), 3);
+    assert_eq!(batch.lane(1), &[4.0, 2.0, 0.0]);
+    assert_eq!(batch.get(0), Some(vector!(3.0, 4.0)));
+    assert_eq!(batch.get(3), None);
+    assert_eq!(batch.to_vectors(), points);
+
+    assert_eq!(batch.magnitude(), [5.0, 2.0, 1.0]);
+    assert_eq!(batch.dot(&batch), [25.0, 4.0, 1.0]);
+    assert_eq!(
+        batch.normalize().to_vectors(),
+        points.map(|x| x.normalize())
+    );
+
+    let sum = batch.clone() + batch.clone() * 2.0 - vector!(1.0, 1.0);
+    assert_eq!(sum.get(1), Some(vector!(-1.0, 5.0)));
+    assert_eq!((sum / 2.0 + 1.0).get(2), Some(vector!(2.0, 0.5)));
+}
+
+#[test]
+fn test_const_ops() {
+    const OFFSET: Vector<i32, 2> = Vector::new([1, 2]);
+    const NEIGHBORS: [Vector<i32, 2>; 3] = [
+        OFFSET.const_add(Vector::<i32, 2>::const_splat(1)),
+        OFFSET.const_sub(Vector::new([3, 3])).const_neg(),
+        OFFSET.const_mul(Vector::new([2, 3])).const_mul_scalar(2),
+    ];
+
+    assert_eq!(NEIGHBORS, [vector!(2, 3), vector!(2, 1), vector!(4, 12)]);
+}
+
+#[test]
+fn test_layout() {
+    use crate::Aligned16;
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Vector<f32, 3>>(), size_of::<[f32; 3]>());
+    assert_eq!(align_of::<Vector<f32, 3>>(), align_of::<[f32; 3]>());
+    assert_eq!(size_of::<Aligned16<Vector<f32, 4>>>(), 16);
+    assert_eq!(align_of::<Aligned16<Vector<f32, 4>>>(), 16);
+    assert_eq!(align_of::<Aligned16<Vector<f32, 3>>>(), 16);
+}
+
+#[test]
+fn test_approx_eq() {
+    let a = vector!(0.1 + 0.2, 1e10, 0.0);
+    let b = vector!(0.3, 1e10 + 1.0, 0.0);
+
+    assert_ne!(a, b);
+    assert!(!a.approx_eq(&b, 1e-9));
+    assert!(a.approx_eq(&b, 1.0));
+    assert!(a.approx_eq_relative(&b, 1e-9));
+    assert!(!a.approx_eq_relative(&vector!(0.3, 1e10, 1e-300), 1e-9));
+}
+
+#[test]
+fn test_float_extrema() {
+    let a = vector!(3.0, f64::NAN, -1.0, 7.0);
+
+    assert_eq!(a.fmin_component(), -1.0);
+    assert_eq!(a.fmax_component(), 7.0);
+    assert_eq!(a.fargmin(), Some(2));
+    assert_eq!(a.fargmax(), Some(3));
+
+    let nan = vector!(f32::NAN, f32::NAN);
+    assert!(nan.fmin_component().is_nan());
+    assert_eq!(nan.fargmax(), None);
+}
+
+#[test]
+fn test_float_inspection() {
+    let a = vector!(1.0, f64::NAN, f64::INFINITY);
+
+    assert!(!a.is_finite());
+    assert!(a.has_nan());
+    assert_eq!(a.is_nan_mask(), vector!(false, true, false));
+    assert!(vector!(1.0, 2.0).is_finite());
+    assert!(!vector!(1.0, f32::NEG_INFINITY).has_nan());
+}
+
+#[test]
+fn test_try_normalize() {
+    assert_eq!(
+        vector!(0.0, 0.0, 2.0).try_normalize(),
+        Some(vector!(0.0, 0.0, 1.0))
+    );
+    assert_eq!(vector!(0.0, 0.0, 0.0).try_normalize(), None);
+    assert!(vector!(0.0f32, 0.0).normalize().has_nan());
+}
+
+#[test]
+fn test_try_from_iter() {
+    use crate::WrongLength;
+
+    assert_eq!(Vector::try_from_iter(1..=3), Ok(vector!(1, 2, 3)));
+    assert_eq!(
+        Vector::<i32, 3>::try_from_iter(1..=2),
+        Err(WrongLength {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        Vector::<i32, 3>::try_from_iter(1..),
+        Err(WrongLength {
+            expected: 3,
+            found: 4
+        })
+    );
+
+    let mut pulled = 0;
+    let result = Vector::<i32, 3>::try_from_iter((1..).inspect(|_| pulled += 1));
+    assert!(result.is_err());
+    assert_eq!(pulled, 4);
+    assert_eq!(
+        Vector::try_from_iter(["a".to_owned()]),
+        Ok(vector!("a".to_owned()))
+    );
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "strict"))]
+fn test_overflow_location() {
+    use std::{panic, sync::Mutex};
+
+    static PANIC: Mutex<Option<(String, u32, String)>> = Mutex::new(None);
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        let message = info.payload_as_str().unwrap_or_default().to_owned();
+        *PANIC.lock().unwrap() = Some((location.file().to_owned(), location.line(), message));
+    }));
+
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| vector!(250u8, 1) + vector!(10, 1));
+    panic::set_hook(hook);
+
+    let message = if cfg!(feature = "strict") {
+        "vector addition overflowed: (250, 1) + (10, 1)"
+    } else {
+        "attempt to add with overflow"
+    };
+    assert!(result.is_err());
+    assert_eq!(
+        PANIC.lock().unwrap().clone(),
+        Some((file!().to_owned(), line, message.to_owned()))
+    );
+}
+
+#[test]
+#[cfg(feature = "strict")]
+fn test_strict() {
+    use std::{
+        hint::black_box,
+        panic::{catch_unwind, AssertUnwindSafe},
+    };
+
+    fn message(f: impl FnOnce()) -> String {
+        let payload = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+        *payload.downcast::<String>().unwrap()
+    }
+
+    let a = vector!(1i8, -100);
+    assert_eq!(
+        message(|| {
+            let _ = a - vector!(0, 100);
+        }),
+        "vector subtraction overflowed: (1, -100) - (0, 100)"
+    );
+    assert_eq!(
+        message(|| {
+            let _ = a * 2;
+        }),
+        "vector multiplication overflowed: (1, -100) * (2, 2)"
+    );
+    assert_eq!(
+        message(|| {
+            let _ = vector!(1u32, 2) / vector!(1, 0);
+        }),
+        "vector division overflowed or divided by zero: (1, 2) / (1, 0)"
+    );
+    assert_eq!(
+        message(|| {
+            let _ = vector!(i64::MIN) % black_box(-1);
+        }),
+        "vector remainder overflowed or divided by zero: (-9223372036854775808) % (-1)"
+    );
+    assert_eq!(
+        message(|| {
+            let mut b = vector!(u16::MAX, 0);
+            b += vector!(1, 1);
+        }),
+        "vector addition overflowed: (65535, 0) + (1, 1)"
+    );
+
+    assert_eq!(a + vector!(126, 100), vector!(127, 0));
+    assert_eq!(
+        vector!(1.0, 2.0) / 0.0,
+        vector!(f64::INFINITY, f64::INFINITY)
+    );
+    assert_eq!(vector!(f32::MAX) * 2.0, vector!(f32::INFINITY));
+}
+
+#[test]
+fn test_centroid() {
+    let points = [vector!(1, 2), vector!(3, 4), vector!(5, 0)];
+
+    assert_eq!(Vector::centroid(points), Some(vector!(3, 2)));
+    assert_eq!(Vector::<f32, 3>::centroid([]), None);
+    assert_eq!(Vector::centroid(vec![vector!(0u8); 300]), None);
+}
+
+#[test]
+fn test_extents() {
+    let points = [vector!(1.0, 5.0, -1.0), vector!(-2.0, 3.0, 0.0)];
+
+    assert_eq!(
+        Vector::extents(points),
+        Some((vector!(-2.0, 3.0, -1.0), vector!(1.0, 5.0, 0.0)))
+    );
+    assert_eq!(
+        Vector::extents([vector!(1, 2)]),
+        Some((vector!(1, 2), vector!(1, 2)))
+    );
+    assert_eq!(Vector::<i32, 2>::extents([]), None);
+}
+
+#[test]
+fn test_variance() {
+    let points = [
+        vector!(2.0, 1.0),
+        vector!(4.0, 1.0),
+        vector!(4.0, 1.0),
+        vector!(4.0, 1.0),
+        vector!(5.0, 1.0),
+        vector!(5.0, 1.0),
+        vector!(7.0, 1.0),
+        vector!(9.0, 1.0),
+    ];
+
+    assert_eq!(Vector::variance(points), Some(vector!(4.0, 0.0)));
+    assert_eq!(Vector::std_dev(points), Some(vector!(2.0, 0.0)));
+    assert_eq!(Vector::<f64, 2>::std_dev([]), None);
+}
+
+#[test]
+fn test_convex_hull() {
+    use crate::geometry::convex_hull;
+
+    let points = Vector::iter_box(vector!(0, 0), vector!(3, 3)).collect::<Vec<_>>();
+    assert_eq!(
+        convex_hull(&points),
+        [vector!(0, 0), vector!(3, 0), vector!(3, 3), vector!(0, 3)]
+    );
+
+    let points = [
+        vector!(0.0, 0.0),
+        vector!(1.0, -1.0),
+        vector!(0.5, 0.5),
+        vector!(2.0, 0.0),
+        vector!(1.0, 1.0),
+        vector!(2.0, 0.0),
+    ];
+    assert_eq!(
+        convex_hull(&points),
+        [
+            vector!(0.0, 0.0),
+            vector!(1.0, -1.0),
+            vector!(2.0, 0.0),
+            vector!(1.0, 1.0)
+        ]
+    );
+
+    assert_eq!(
+        convex_hull(&[vector!(1, 1), vector!(1, 1)]),
+        [vector!(1, 1)]
+    );
+    assert_eq!(
+        convex_hull(&[vector!(0, 0), vector!(1, 1), vector!(2, 2)]).len(),
+        2
+    );
+}
+
+#[test]
+fn test_kd_tree() {
+    use crate::KdTree;
+
+    // Deterministic pseudo-random points
+    let mut seed = 12345u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 16) as i32 % 100
+    };
+    let points = (0..200)
+        .map(|i| (vector!(next(), next(), next()), i))
+        .collect::<Vec<_>>();
+    let tree = points.iter().copied().collect::<KdTree<_, 3, _>>();
+    assert_eq!(tree.len(), 200);
+
+    let distance = |a: &Vector<i32, 3>, b: &Vector<i32, 3>| (*a - *b).magnitude_squared();
+    for target in [vector!(0, 0, 0), vector!(50, -20, 75), vector!(99, 99, 99)] {
+        let mut expected = points.clone();
+        expected.sort_by_key(|(p, _)| distance(p, &target));
+
+        let nearest = tree.nearest(&target).unwrap();
+        assert_eq!(
+            distance(nearest.0, &target),
+            distance(&expected[0].0, &target)
+        );
+
+        let k_nearest = tree.k_nearest(&target, 10);
+        assert_eq!(k_nearest.len(), 10);
+        for (a, b) in k_nearest.iter().zip(expected.iter()) {
+            assert_eq!(distance(a.0, &target), distance(&b.0, &target));
+        }
+
+        let mut radius = tree
+            .within_radius(&target, 30)
+            .iter()
+            .map(|x| *x.1)
+            .collect::<Vec<_>>();
+        radius.sort();
+        let mut expected_radius = points
+            .iter()
+            .filter(|(p, _)| distance(p, &target) <= 900)
+            .map(|x| x.1)
+            .collect::<Vec<_>>();
+        expected_radius.sort();
+        assert_eq!(radius, expected_radius);
+    }
+
+    let (min, max) = (vector!(10, 20, 30), vector!(60, 70, 80));
+    let mut inside = tree
+        .within_box(&min, &max)
+        .iter()
+        .map(|x| *x.1)
+        .collect::<Vec<_>>();
+    inside.sort();
+    let expected = points
+        .iter()
+        .filter(|(p, _)| {
+            (0..3).all(|i| {
+                min.as_slice()[i] <= p.as_slice()[i] && p.as_slice()[i] <= max.as_slice()[i]
+            })
+        })
+        .map(|x| x.1)
+        .collect::<Vec<_>>();
+    assert_eq!(inside, expected);
+
+    assert!(KdTree::<f32, 2, ()>::new([])
+        .nearest(&vector!(0.0, 0.0))
+        .is_none());
+
+    let origin = Vector::<i32, 0>::new([]);
+    let tree = KdTree::new([(origin, 'a'), (origin, 'b'), (origin, 'c')]);
+    assert_eq!(tree.nearest(&origin), Some((&origin, &'a')));
+    assert_eq!(tree.k_nearest(&origin, 2).len(), 2);
+    assert_eq!(tree.within_radius(&origin, 0).len(), 3);
+    assert_eq!(tree.within_box(&origin, &origin).len(), 3);
+}
+
+#[test]
+fn test_spatial_hash() {
+    use crate::SpatialHash;
+
+    let mut grid = SpatialHash::new(4);
+    let points = Vector::iter_box(vector!(-10, -10), vector!(10, 10)).collect::<Vec<_>>();
+    let ids = points
+        .iter()
+        .map(|&p| grid.insert(p, p.x() * 100 + p.y()))
+        .collect::<Vec<_>>();
+    assert_eq!(grid.len(), 441);
+    assert_eq!(grid.cell(&vector!(-1, 4)), vector!(-1, 1));
+
+    let center = vector!(-3, 2);
+    let mut found = grid
+        .query_radius(&center, 5)
+        .iter()
+        .map(|x| *x.1)
+        .collect::<Vec<_>>();
+    found.sort_by_key(|x| (x.y(), x.x()));
+    let expected = points
+        .iter()
+        .copied()
+        .filter(|p| (*p - center).magnitude_squared() <= 25)
+        .collect::<Vec<_>>();
+    assert_eq!(found, expected);
+
+    assert_eq!(grid.remove(ids[0]), Some((vector!(-10, -10), -1010)));
+    assert_eq!(grid.remove(ids[0]), None);
+    assert_eq!(grid.get(ids[1]), Some((&vector!(-9, -10), &-910)));
+    assert!(grid.query_radius(&vector!(-10, -10), 0).is_empty());
+
+    let id = grid.insert(vector!(100, 100), 0);
+    assert_ne!(id, ids[0]);
+    assert_eq!(grid.get(ids[0]), None);
+    assert_eq!(grid.remove(ids[0]), None);
+    assert_eq!(grid.get(id), Some((&vector!(100, 100), &0)));
+    assert_eq!(grid.query_radius(&vector!(99, 99), 2)[0].0, id);
+    assert!(grid.query_radius(&vector!(0, 0), -1).is_empty());
+    assert_eq!(grid.query_radius(&vector!(0, 0), 40_000).len(), 441);
+
+    let mut grid = SpatialHash::new(1.0);
+    grid.insert(vector!(0.5, 0.5), ());
+    grid.insert(vector!(1e300, -1e300), ());
+    assert_eq!(
+        grid.cell(&vector!(1e300, -1e300)),
+        vector!(i64::MAX, i64::MIN)
+    );
+    assert_eq!(
+        grid.query_radius(&vector!(0.0, 0.0), f64::INFINITY).len(),
+        2
+    );
+    assert_eq!(grid.query_radius(&vector!(0.0, 0.0), 1e100).len(), 1);
+    assert!(grid.query_radius(&vector!(0.0, 0.0), f64::NAN).is_empty());
+}
+
+#[test]
+fn test_slice_ops() {
+    use crate::slice::*;
+
+    let a = [vector!(0.0, 0.0), vector!(1.0, 2.0)];
+    let b = [vector!(3.0, 4.0), vector!(1.0, 1.0)];
+
+    assert_eq!(dot_slices(&a, &b), [0.0, 3.0]);
+    assert_eq!(distances(&a, &b), [5.0, 1.0]);
+    assert_eq!(
+        pairwise_distances(&a, &b),
+        [5.0, 2f64.sqrt(), 8f64.sqrt(), 1.0]
+    );
+}
+
+#[test]
+fn test_bytes() {
+    let a = vector!(0x01020304u32, 0xAABBCCDD);
+
+    assert_eq!(a.to_le_bytes(), [[4, 3, 2, 1], [0xDD, 0xCC, 0xBB, 0xAA]]);
+    assert_eq!(a.to_be_bytes(), [[1, 2, 3, 4], [0xAA, 0xBB, 0xCC, 0xDD]]);
+    assert_eq!(Vector::from_le_bytes(&a.to_le_bytes()), a);
+    assert_eq!(Vector::from_be_bytes(&a.to_be_bytes()), a);
+
+    let b = vector!(1.5f64, -2.0, 0.0);
+    assert_eq!(Vector::<f64, 3>::from_be_bytes(&b.to_be_bytes()), b);
+}
+
+#[test]
+#[cfg(feature = "geo-types")]
+fn test_geo_types() {
+    use geo_types::{Coord, Point};
+
+    let a = vector!(1.5, -2.0);
+    let coord = Coord::from(a);
+    assert_eq!(coord, Coord { x: 1.5, y: -2.0 });
+    assert_eq!(Vector::from(coord), a);
+    assert_eq!(Vector::from(Point::from(a)), a);
+    assert_eq!(a.to_wkt(), "POINT(1.5 -2)");
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn test_defmt() {
+    // Actually formatting needs a global logger, which only exists on embedded targets
+    fn assert_format<T: defmt::Format>(_: &T) {}
+
+    assert_format(&vector!(1u8, 2, 3));
+    assert_format(&vector!(1.5f32, -2.0));
+    assert_format(&vector!(true, false));
+}
+
+#[test]
+#[cfg(feature = "emath")]
+fn test_emath() {
+    use ::emath::{Pos2, Vec2};
+
+    let a = vector!(1.5f32, -2.0);
+    assert_eq!(Vec2::from(a), Vec2::new(1.5, -2.0));
+    assert_eq!(Vector::from(Vec2::from(a)), a);
+    assert_eq!(Pos2::from(a), Pos2::new(1.5, -2.0));
+    assert_eq!(Vector::from(Pos2::from(a)), a);
+}
+
+#[test]
+#[cfg(feature = "euclid")]
+fn test_euclid_units() {
+    use euclid::{default, Point2D, Size3D, UnknownUnit, Vector2D, Vector3D};
+
+    struct ScreenSpace;
+
+    let a = vector!(1.5, -2.0);
+    let screen: Vector2D<f64, ScreenSpace> = a.into();
+    assert_eq!(screen, Vector2D::new(1.5, -2.0));
+    assert_eq!(Vector::from(screen), a);
+    assert_eq!(Vector::from(Point2D::<_, UnknownUnit>::from(a)), a);
+
+    let b = vector!(1, 2, 3);
+    assert_eq!(default::Vector3D::from(b), Vector3D::new(1, 2, 3));
+    assert_eq!(Vector::from(default::Point3D::from(b)), b);
+    assert_eq!(Size3D::<_, ScreenSpace>::from(b).depth, 3);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde() {
+    use serde::{Deserialize, Serialize};
+
+    let a = vector!(1, -2, 3);
+    assert_eq!(serde_json::to_string(&a).unwrap(), "[1,-2,3]");
+    assert_eq!(
+        serde_json::from_str::<Vector<i32, 3>>("[1,-2,3]").unwrap(),
+        a
+    );
+    assert!(serde_json::from_str::<Vector<i32, 3>>("[1,2]").is_err());
+    assert!(serde_json::from_str::<Vector<i32, 3>>("[1,2,3,4]").is_err());
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Named<const N: usize> {
+        #[serde(with = "crate::serde_named")]
+        value: Vector<i32, N>,
+    }
+
+    let named = Named { value: a };
+    let json = serde_json::to_string(&named).unwrap();
+    assert_eq!(json, r#"{"value":{"x":1,"y":-2,"z":3}}"#);
+    assert_eq!(serde_json::from_str::<Named<3>>(&json).unwrap(), named);
+    assert_eq!(
+        serde_json::from_str::<Named<2>>(r#"{"value":{"y":2,"x":1}}"#).unwrap(),
+        Named {
+            value: vector!(1, 2)
+        }
+    );
+    assert!(serde_json::from_str::<Named<2>>(r#"{"value":{"x":1}}"#).is_err());
+    assert!(serde_json::from_str::<Named<2>>(r#"{"value":{"x":1,"y":2,"z":3}}"#).is_err());
+    assert!(serde_json::from_str::<Named<2>>(r#"{"value":{"x":1,"x":2}}"#).is_err());
+    assert!(serde_json::to_string(&Named {
+        value: Vector::<i32, 5>::zero()
+    })
+    .is_err());
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn test_zerocopy() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let a = vector!(1u16, 2, 3);
+    assert_eq!(a.as_bytes(), [1u16, 2, 3].as_bytes());
+    assert_eq!(Vector::<u16, 3>::read_from_bytes(a.as_bytes()), Ok(a));
+
+    let points = [vector!(1.0f32, 2.0), vector!(3.0, 4.0)];
+    let bytes = points.as_bytes();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(
+        <[Vector<f32, 2>]>::ref_from_bytes(bytes).unwrap(),
+        points.as_slice()
+    );
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn test_random() {
+    use rand::rand_core::{utils, TryRng};
+
+    struct XorShift(u64);
+    impl TryRng for XorShift {
+        type Error = std::convert::Infallible;
+
+        fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+            Ok((self.try_next_u64()? >> 32) as u32)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            Ok(self.0)
+        }
+
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+            utils::fill_bytes_via_next_word(dst, || self.try_next_u64())
+        }
+    }
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100 {
+        let unit = Vector::<f64, 4>::random_unit(&mut rng);
+        assert!((unit.magnitude() - 1.0).abs() < 1e-9);
+        assert!(Vector::<f32, 3>::random_in_ball(&mut rng).magnitude() <= 1.0);
+
+        let point = Vector::random_in_box(&mut rng, vector!(-2, 5), vector!(2, 5));
+        assert!((-2..=2).contains(&point.x()) && point.y() == 5);
+    }
+}
+
+#[test]
+fn test_signed_angle_to() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    let a = vector!(1.0, 0.0);
+    let radians = |x: &Vector<f64, 2>| a.signed_angle_to(x).radians();
+    assert_eq!(radians(&vector!(1.0, 1.0)), FRAC_PI_4);
+    assert_eq!(radians(&vector!(0.0, -2.0)), -FRAC_PI_2);
+    assert_eq!(radians(&vector!(-1.0, 0.0)), PI);
+    assert_eq!(radians(&vector!(-1.0, -0.0)), PI);
+    assert_eq!(
+        vector!(0.0, 1.0).signed_angle_to(&vector!(1.0, 0.0)),
+        Angle::from_radians(-FRAC_PI_2)
+    );
+}
+
+#[test]
+fn test_faceforward() {
+    let normal = vector!(0, 1, 0);
+    let reference = vector!(1, 1, 0);
+    assert_eq!(normal.faceforward(&vector!(0, -1, 0), &reference), normal);
+    assert_eq!(normal.faceforward(&vector!(0, 1, 0), &reference), -normal);
+    assert_eq!(normal.faceforward(&vector!(0, 0, 1), &reference), -normal);
+    assert_eq!(normal.faceforward(&vector!(-1, 0, 0), &reference), normal);
+}
+
+#[test]
+fn test_argmin_argmax() {
+    let a = vector!(4, -9, 2, 4);
+    assert_eq!(a.argmin(), 1);
+    assert_eq!(a.argmax(), 0);
+    assert_eq!(a.argmin_abs(), 2);
+    assert_eq!(a.argmax_abs(), 1);
+    assert_eq!(vector!(5u8, 5, 5).argmax(), 0);
+
+    let b = vector!(1.5, f64::NAN, -3.0, 0.5);
+    assert_eq!(b.fargmin_abs(), Some(3));
+    assert_eq!(b.fargmax_abs(), Some(2));
+}
+
+#[test]
+fn test_dominant_axis() {
+    assert_eq!(vector!(1, -5, 3).dominant_axis(), 1);
+    assert_eq!(vector!(2, -2).dominant_axis(), 0);
+    assert_eq!(vector!(1, -5, 3).dominant_axis_vector(), vector!(0, -1, 0));
+    assert_eq!(
+        vector!(0.1, 0.2, 0.7).dominant_axis_vector(),
+        vector!(0.0, 0.0, 1.0)
+    );
+    assert_eq!(vector!(0, 0).dominant_axis_vector(), vector!(0, 0));
+    assert_eq!(vector!(0.0, 0.0).dominant_axis_vector(), vector!(0.0, 0.0));
+}
+
+#[test]
+fn test_sorted() {
+    let a = vector!(5, -1, 5, 0);
+    assert_eq!(a.sorted(), vector!(-1, 0, 5, 5));
+    assert_eq!(a.argsort(), [1, 3, 0, 2]);
+
+    let b = vector!(2.0, f64::NAN, -1.0);
+    assert_eq!(b.fargsort(), [2, 0, 1]);
+    let sorted = b.fsorted();
+    assert_eq!((sorted.x(), sorted.y()), (-1.0, 2.0));
+    assert!(sorted.z().is_nan());
+}
+
+#[test]
+fn test_permute() {
+    let a = vector!(1, 2, 3, 4);
+    assert_eq!(a.permute([3, 0, 2, 1]), vector!(4, 1, 3, 2));
+    assert_eq!(a.permute([0, 0, 1, 1]), vector!(1, 1, 2, 2));
+    assert_eq!(a.reversed(), vector!(4, 3, 2, 1));
+    assert_eq!(a.permute(a.sorted().argsort()), a);
+}
+
+#[test]
+fn test_snap_to_grid() {
+    let a = vector!(7.4, -2.6, 0.2);
+    assert_eq!(a.snap_to_grid(2.0), vector!(8.0, -2.0, 0.0));
+    assert_eq!(a.floor_to_grid(2.0), vector!(6.0, -4.0, 0.0));
+    assert_eq!(
+        a.snap_to_grid_per_axis(&vector!(1.0, 0.5, 0.25)),
+        vector!(7.0, -2.5, 0.25)
+    );
+    assert_eq!(
+        a.floor_to_grid_per_axis(&vector!(1.0, 0.5, 0.25)),
+        vector!(7.0, -3.0, 0.0)
+    );
+}
+
+#[test]
+fn test_round_to_multiple() {
+    let step = vector!(16, 16);
+    assert_eq!(vector!(7, 8).round_to_multiple(&step), vector!(0, 16));
+    assert_eq!(vector!(-8, -9).round_to_multiple(&step), vector!(0, -16));
+    assert_eq!(vector!(-24, 40).round_to_multiple(&step), vector!(-16, 48));
+    assert_eq!(
+        vector!(5u32, 3).round_to_multiple(&vector!(4, 4)),
+        vector!(4, 4)
+    );
+}
+
+#[test]
+fn test_wrapped() {
+    let size = vector!(4, 3);
+    assert_eq!(vector!(5, -1).wrapped(&size), vector!(1, 2));
+    assert_eq!(vector!(-8, 3).wrapped(&size), vector!(0, 0));
+    assert_eq!(
+        vector!(1.5, -0.5).wrapped(&vector!(1.0, 2.0)),
+        vector!(0.5, 1.5)
+    );
+    assert_eq!(
+        vector!(-1e-20, 0.0).wrapped(&vector!(1.0, 1.0)),
+        vector!(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_lerp() {
+    let (a, b) = (vector!(0.0, 10.0), vector!(4.0, 20.0));
+    assert_eq!(a.lerp(&b, 0.5), vector!(2.0, 15.0));
+    assert_eq!(
+        Vector::inverse_lerp(&a, &b, &a.lerp(&b, 0.75)),
+        vector!(0.75, 0.75)
+    );
+
+    let remapped = vector!(0.5, -1.0).remap(
+        &vector!(0.0, -2.0),
+        &vector!(1.0, 2.0),
+        &vector!(10.0, 0.0),
+        &vector!(20.0, -8.0),
+    );
+    assert_eq!(remapped, vector!(15.0, -2.0));
+}
+
+#[test]
+fn test_cosine_similarity() {
+    let a = vector!(1.0f64, 2.0, 3.0);
+    assert!((a.cosine_similarity(&(a * 3.0)) - 1.0).abs() < 1e-12);
+    assert!((a.cosine_similarity(&-a) + 1.0).abs() < 1e-12);
+    assert_eq!(vector!(1.0, 0.0).cosine_similarity(&vector!(0.0, 5.0)), 0.0);
+    assert_eq!(
+        Vector::<f32, 3>::zero().cosine_similarity(&Vector::zero()),
+        0.0
+    );
+}
+
+#[test]
+fn test_project_onto_plane() {
+    let normal = vector!(1.0, 1.0, 0.0);
+    let projected = vector!(2.0, 0.0, 5.0).project_onto_plane(&normal);
+    assert_eq!(projected, vector!(1.0, -1.0, 5.0));
+    assert_eq!(projected.dot(&normal), 0.0);
+    assert_eq!(
+        vector!(0.0, 3.0).project_onto_plane(&vector!(0.0, -1.0)),
+        vector!(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let inputs = [
+        vector!(0.0f64, 0.0, 1.0),
+        vector!(0.0, 0.0, -1.0),
+        vector!(1.0, 2.0, 3.0),
+        vector!(-4.0, 0.5, -1e-9),
+    ];
+
+    for normal in inputs {
+        let (tangent, bitangent) = normal.orthonormal_basis();
+        let normal = normal.normalize();
+        for (a, b) in [(normal, tangent), (normal, bitangent), (tangent, bitangent)] {
+            assert!(a.dot(&b).abs() < 1e-12);
+        }
+        for v in [tangent, bitangent] {
+            assert!((v.magnitude() - 1.0).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let vectors = [
+        vector!(1.0, 1.0, 0.0),
+        vector!(2.0, 2.0, 0.0),
+        vector!(1.0, 0.0, 1.0),
+        vector!(0.0, 1.0, 1.0),
+        vector!(5.0, 5.0, 5.0),
+    ];
+    let basis = Vector::gram_schmidt(&vectors, 1e-9);
+    assert_eq!(basis.len(), 3);
+    for (i, a) in basis.iter().enumerate() {
+        assert!((a.magnitude() - 1.0f64).abs() < 1e-12);
+        for b in &basis[i + 1..] {
+            assert!(a.dot(b).abs() < 1e-12);
+        }
+    }
+
+    assert!(Vector::gram_schmidt(&[Vector::<f64, 2>::zero()], 1e-9).is_empty());
+}
+
+#[test]
+fn test_parallel_perpendicular() {
+    let a = vector!(1.0, 2.0, 3.0);
+    assert!(a.is_parallel_to(&(a * 1e6), 1e-9));
+    assert!(a.is_parallel_to(&-a, 1e-9));
+    assert!(!a.is_parallel_to(&vector!(1.0, 2.0, 3.1), 1e-3));
+    assert!(a.is_parallel_to(&vector!(1.0, 2.0, 3.001), 1e-3));
+    assert!(a.is_parallel_to(&Vector::zero(), 1e-9));
+
+    assert!(a.is_perpendicular_to(&vector!(3.0, 0.0, -1.0), 1e-9));
+    assert!(!a.is_perpendicular_to(&vector!(3.0, 0.0, -0.9), 1e-3));
+    assert!(a.is_perpendicular_to(&Vector::zero(), 1e-9));
+}
+
+#[test]
+fn test_step() {
+    let a = vector!(1, 5, 3);
+    assert_eq!(a.step(&vector!(2, 5, 2)), vector!(0, 1, 1));
+    assert_eq!(a.step_scalar(3), vector!(0, 1, 1));
+    assert_eq!(a.greater_than_scalar(3), vector!(false, true, false));
+    assert_eq!(
+        a.greater_than(&vector!(0, 6, 2)),
+        vector!(true, false, true)
+    );
+
+    let mask = vector!(true, false, true);
+    assert_eq!(mask.select(&a, &vector!(-1, -1, -1)), vector!(1, -1, 3));
+}
+
+#[test]
+fn test_lerp_per_component() {
+    let (a, b) = (vector!(0.0, 10.0, -4.0), vector!(8.0, 20.0, 4.0));
+    assert_eq!(
+        a.lerp_per_component(&b, &vector!(0.5, 0.0, 1.0)),
+        vector!(4.0, 10.0, 4.0)
+    );
+    assert_eq!(
+        a.lerp_per_component(&b, &vector!(0.5, 0.5, 0.5)),
+        a.lerp(&b, 0.5)
+    );
+}
+
+#[test]
+fn test_bitwise() {
+    let pos = vector!(37, -5, 16);
+    assert_eq!(pos & 15, vector!(5, 11, 0));
+    assert_eq!(pos | vector!(2, 0, 1), vector!(39, -5, 17));
+    assert_eq!(vector!(0b1100u8, 0b1010) ^ 0b0110, vector!(0b1010, 0b1100));
+    assert_eq!(!vector!(0u8, 255), vector!(255, 0));
+    assert_eq!(!vector!(true, false), vector!(false, true));
+    assert_eq!(
+        vector!(true, true) & vector!(true, false),
+        vector!(true, false)
+    );
+
+    let mut a = vector!(0b1111u32, 0b1010);
+    a &= 0b0110;
+    a |= vector!(1, 0);
+    a ^= vector!(0, 0b0010);
+    assert_eq!(a, vector!(0b0111, 0b0000));
+}
+
+#[test]
+fn test_shift() {
+    let pos = vector!(37, -5, 16);
+    assert_eq!(pos >> 4, vector!(2, -1, 1));
+    assert_eq!(pos >> 4, pos.div_euclid_scalar(16));
+    assert_eq!(vector!(1u8, 3) << 2, vector!(4, 12));
+
+    let mut a = vector!(1u64, 2, 3);
+    a <<= 3;
+    a >>= 1;
+    assert_eq!(a, vector!(4, 8, 12));
+}
+
+#[test]
+fn test_checked_neg() {
+    assert_eq!(vector!(3, -4).checked_neg(), Some(vector!(-3, 4)));
+    assert_eq!(vector!(i32::MIN, 1).checked_neg(), None);
+    assert_eq!(vector!(0u32, 0).checked_neg(), Some(vector!(0, 0)));
+    assert_eq!(vector!(0u32, 7).checked_neg(), None);
+    assert_eq!(-vector!(1.5, -2.0), vector!(-1.5, 2.0));
+}
+
+#[test]
+fn test_magnitude_stable() {
+    assert_eq!(vector!(3.0, 4.0).magnitude_stable(), 5.0);
+    assert_eq!(vector!(3e-200, -4e-200).magnitude_stable(), 5e-200);
+    assert_eq!(vector!(f32::MAX, 0.0).magnitude_stable(), f32::MAX);
+    assert_eq!(Vector::<f64, 3>::zero().magnitude_stable(), 0.0);
+    assert_eq!(
+        vector!(f64::INFINITY, 1.0).magnitude_stable(),
+        f64::INFINITY
+    );
+}
+
+#[test]
+#[cfg(feature = "half")]
+fn test_half() {
+    use half::{bf16, f16};
+
+    let a = Vector::<f16, 2>::from_f32(&vector!(300.0, 400.0));
+    assert_eq!(a.to_f32(), vector!(300.0, 400.0));
+    assert_eq!(Vector::<f32, 2>::from(a), vector!(300.0, 400.0));
+    assert!(a.magnitude().is_infinite());
+    assert_eq!(a.widened_magnitude(), f16::from_f32(500.0));
+    assert_eq!(a.widened_dot(&a), f16::INFINITY);
+    assert_eq!(
+        a.widened_normalize().to_f32(),
+        vector!(0.6, 0.8).num_cast::<f16>().unwrap().to_f32()
+    );
+    assert_eq!(a + a, Vector::<f16, 2>::from_f32(&vector!(600.0, 800.0)));
+
+    let b = Vector::<bf16, 3>::from_f32(&vector!(1e20, 0.0, 0.0));
+    assert_eq!(b.widened_magnitude(), bf16::from_f32(1e20));
+}
+
+#[test]
+fn test_truncation() {
+    let a = vector!(1, 2, 3);
+    assert_eq!(a.xy(), vector!(1, 2));
+    assert_eq!(a.xz(), vector!(1, 3));
+    assert_eq!(a.yz(), vector!(2, 3));
+    assert_eq!(vector!(1.0, 2.0, 3.0, 1.0).xyz(), vector!(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_matrix() {
+    let a = Matrix::new([[1.0, 2.0, 0.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    assert_eq!(a.row(1), vector!(0.0, 1.0, 4.0));
+    assert_eq!(a.column(0), vector!(1.0, 0.0, 5.0));
+    assert_eq!(a.transpose().row(0), a.column(0));
+    assert_eq!(
+        Matrix::from_columns([a.column(0), a.column(1), a.column(2)]),
+        a
+    );
+    assert_eq!(a.trace(), 2.0);
+    assert!((a.determinant() - 16.0f64).abs() < 1e-12);
+    let product = a * a.inverse().unwrap() - Matrix::identity();
+    assert!((0..3).all(|i| product.row(i).magnitude() < 1e-12));
+    assert_eq!(a * Matrix::<f64, 3, 3>::identity(), a);
+
+    let b = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(b * vector!(1, 0, -1), vector!(-2, -2));
+    assert_eq!(b * b.transpose(), Matrix::new([[14, 32], [32, 77]]));
+    assert_eq!(b + b, b * 2);
+    assert_eq!(Matrix::new([[2.0, 4.0], [1.0, 2.0]]).determinant(), 0.0);
+    assert_eq!(format!("{:?}", b), "[(1, 2, 3), (4, 5, 6)]");
+}
+
+#[test]
+fn test_affine() {
+    let rotate = Affine2::from_linear(Matrix::new([[0.0, -1.0], [1.0, 0.0]]));
+    let translate = Affine2::from_translation(vector!(2.0, 3.0));
+    let transform = translate * rotate;
+
+    let point = vector!(1.0, 0.0);
+    assert_eq!(transform.transform_point(&point), vector!(2.0, 4.0));
+    assert_eq!(transform.transform_vector(&point), vector!(0.0, 1.0));
+    assert_eq!(
+        (rotate * translate).transform_point(&point),
+        vector!(-3.0, 3.0)
+    );
+
+    let inverse = transform.inverse().unwrap();
+    assert_eq!(inverse.transform_point(&vector!(2.0, 4.0)), point);
+    assert_eq!(inverse * transform, Affine::identity());
+
+    let singular = Affine3::from_linear(Matrix::from_diagonal(vector!(1.0, 0.0, 1.0)));
+    assert_eq!(singular.inverse(), None);
+}
+
+#[test]
+fn test_rotation() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    let a = Rotation2::from_angle(Angle::from_radians(FRAC_PI_4));
+    assert!(((a * a).angle().radians() - FRAC_PI_2).abs() < 1e-12);
+    assert!((a * a.inverse())
+        .apply(&vector!(3.0, 4.0))
+        .approx_eq(&vector!(3.0, 4.0), 1e-12));
+    assert!(a.matrix() * vector!(1.0, 0.0) == a.apply(&vector!(1.0, 0.0)));
+    let between = Rotation2::between(&vector!(1.0, 1.0), &vector!(-2.0, 0.0));
+    assert!((between.angle().degrees() - 135.0f64).abs() < 1e-12);
+    assert_eq!(
+        Rotation2::identity().apply(&vector!(1.0, 2.0)),
+        vector!(1.0, 2.0)
+    );
+
+    let b = Rotation3::from_axis_angle(&vector!(0.0, 0.0, 2.0), Angle::from_degrees(90.0));
+    let c = Rotation3::from_axis_angle(&vector!(1.0, 0.0, 0.0), Angle::from_degrees(180.0));
+    let v = vector!(1.0, 0.0, 0.0);
+    assert!((c * b).apply(&v).approx_eq(&vector!(0.0, -1.0, 0.0), 1e-12));
+    assert!((b * c).apply(&v).approx_eq(&vector!(0.0, 1.0, 0.0), 1e-12));
+    assert!((b.inverse() * b).apply(&v).approx_eq(&v, 1e-12));
+
+    assert!(Rotation3::from_matrix(b.matrix(), 1e-12).is_some());
+    assert!(Rotation3::from_matrix(Matrix::from_diagonal(vector!(2.0, 1.0, 1.0)), 1e-12).is_none());
+    assert!(
+        Rotation3::from_matrix(Matrix::from_diagonal(vector!(-1.0, 1.0, 1.0)), 1e-12).is_none()
+    );
+}
+
+#[test]
+fn test_isometry() {
+    let quarter = Angle::from_degrees(90.0f64);
+    let a = Isometry2::new(Rotation2::from_angle(quarter), vector!(2.0, 0.0));
+    let b = Isometry2::from_translation(vector!(0.0, 1.0));
+    let point = vector!(1.0, 0.0);
+
+    assert!((a * b)
+        .transform_point(&point)
+        .approx_eq(&vector!(1.0, 1.0), 1e-12));
+    assert!((b * a)
+        .transform_point(&point)
+        .approx_eq(&vector!(2.0, 2.0), 1e-12));
+    assert!(a
+        .transform_vector(&point)
+        .approx_eq(&vector!(0.0, 1.0), 1e-12));
+    assert!((a.inverse() * a)
+        .transform_point(&point)
+        .approx_eq(&point, 1e-12));
+    assert!((a.rotation().angle() - quarter).radians().abs() < 1e-12);
+    assert_eq!(
+        a.to_affine().transform_point(&point),
+        a.transform_point(&point)
+    );
+
+    let rotation = Rotation3::from_axis_angle(&vector!(0.0, 1.0, 0.0), quarter);
+    let c = Isometry3::new(rotation, vector!(0.0, 0.0, 5.0));
+    assert!(c
+        .transform_point(&vector!(0.0, 0.0, 1.0))
+        .approx_eq(&vector!(1.0, 0.0, 5.0), 1e-12));
+    assert_eq!(c.rotation(), rotation);
+    assert_eq!(c.translation(), vector!(0.0, 0.0, 5.0));
+    assert_eq!(
+        Isometry3::identity().transform_point(&vector!(1.0, 2.0, 3.0)),
+        vector!(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn test_projection() {
+    let perspective = Matrix::perspective(Angle::from_degrees(90.0), 2.0, 1.0, 100.0);
+    let near = perspective.project_to_ndc(&vector!(2.0, 1.0, -1.0));
+    assert!(near.approx_eq(&vector!(1.0, 1.0, -1.0), 1e-12));
+    let far = perspective.project_to_ndc(&vector!(0.0, -100.0, -100.0));
+    assert!(far.approx_eq(&vector!(0.0, -1.0, 1.0), 1e-12));
+
+    let orthographic = Matrix::orthographic(-2.0, 2.0, 0.0, 1.0, 0.5, 10.5);
+    let corner = orthographic.project_to_ndc(&vector!(2.0, 0.0, -0.5));
+    assert!(corner.approx_eq(&vector!(1.0, -1.0, -1.0), 1e-12));
+    let center = orthographic.project_to_ndc(&vector!(0.0, 0.5, -5.5));
+    assert!(center.approx_eq(&vector!(0.0, 0.0, 0.0), 1e-12));
+}
+
+#[test]
+fn test_barycentric() {
+    use crate::geometry::{barycentric, barycentric_interpolate};
+
+    let (a, b, c) = (
+        vector!(0.0, 0.0, 0.0),
+        vector!(2.0, 0.0, 0.0),
+        vector!(0.0, 2.0, 0.0),
+    );
+    assert_eq!(barycentric(&a, &a, &b, &c), vector!(1.0, 0.0, 0.0));
+    assert_eq!(
+        barycentric(&vector!(1.0, 1.0, 5.0), &a, &b, &c),
+        vector!(0.0, 0.5, 0.5)
+    );
+    let outside = barycentric(&vector!(-1.0, 0.5), &a.xy(), &b.xy(), &c.xy());
+    assert!(outside.x() > 1.0 && outside.y() < 0.0);
+
+    let colors = [
+        vector!(255.0, 0.0, 0.0),
+        vector!(0.0, 255.0, 0.0),
+        vector!(0.0, 0.0, 255.0),
+    ];
+    let weights = barycentric(&vector!(0.5, 0.5, 0.0), &a, &b, &c);
+    assert_eq!(
+        barycentric_interpolate(&weights, &colors),
+        vector!(127.5, 63.75, 63.75)
+    );
+}
+
+#[test]
+fn test_triangle() {
+    let flat = Triangle::new(vector!(0.0, 0.0), vector!(3.0, 0.0), vector!(0.0, 3.0));
+    assert_eq!(flat.area(), 4.5);
+    assert_eq!(flat.centroid(), vector!(1.0, 1.0));
+    assert!(flat.contains_point(&vector!(1.5, 1.5)));
+    assert!(!flat.contains_point(&vector!(2.0, 2.0)));
+
+    assert_eq!(flat.closest_point(&vector!(-1.0, -1.0)), vector!(0.0, 0.0));
+    assert_eq!(flat.closest_point(&vector!(5.0, -1.0)), vector!(3.0, 0.0));
+    assert_eq!(flat.closest_point(&vector!(1.0, -2.0)), vector!(1.0, 0.0));
+    assert_eq!(flat.closest_point(&vector!(-2.0, 1.0)), vector!(0.0, 1.0));
+    assert_eq!(flat.closest_point(&vector!(3.0, 3.0)), vector!(1.5, 1.5));
+    assert_eq!(flat.closest_point(&vector!(1.0, 1.0)), vector!(1.0, 1.0));
+
+    let tilted = Triangle::new(
+        vector!(0.0, 0.0, 0.0),
+        vector!(0.0, 2.0, 0.0),
+        vector!(0.0, 0.0, 2.0),
+    );
+    assert_eq!(tilted.normal(), vector!(1.0, 0.0, 0.0));
+    assert_eq!(tilted.area(), 2.0);
+    assert_eq!(
+        tilted.closest_point(&vector!(4.0, 0.5, 0.5)),
+        vector!(0.0, 0.5, 0.5)
+    );
+    assert!(tilted.contains_point(&vector!(0.0, 0.5, 0.5)));
+    assert!(tilted.contains_point(&vector!(1e-12, 0.5, 0.5)));
+    assert!(!tilted.contains_point(&vector!(4.0, 0.5, 0.5)));
+    assert!(!tilted.contains_point(&vector!(-0.01, 0.5, 0.5)));
+    assert!(!tilted.contains_point(&vector!(0.0, 2.0, 2.0)));
+}
+
+#[test]
+fn test_bezier() {
+    let curve = Bezier::cubic(
+        vector!(0.0f64, 0.0),
+        vector!(0.0, 1.0),
+        vector!(1.0, 1.0),
+        vector!(1.0, 0.0),
+    );
+    assert_eq!(curve.degree(), 3);
+    assert_eq!(curve.eval(0.0), vector!(0.0, 0.0));
+    assert_eq!(curve.eval(1.0), vector!(1.0, 0.0));
+    assert_eq!(curve.eval(0.5), vector!(0.5, 0.75));
+    assert_eq!(curve.derivative(0.0), vector!(0.0, 3.0));
+    assert_eq!(curve.derivative(0.5), vector!(1.5, 0.0));
+
+    let (left, right) = curve.split(0.25);
+    assert_eq!(left.degree(), 3);
+    for t in [0.0, 0.3, 0.8, 1.0] {
+        assert!(left.eval(t).approx_eq(&curve.eval(t * 0.25), 1e-12));
+        assert!(right.eval(t).approx_eq(&curve.eval(0.25 + t * 0.75), 1e-12));
+    }
+
+    let line = Bezier::quadratic(
+        vector!(0.0, 0.0, 0.0),
+        vector!(1.0, 1.0, 1.0),
+        vector!(2.0, 2.0, 2.0),
+    );
+    assert!((line.arc_length(4) - 12f64.sqrt()).abs() < 1e-12);
+    assert!((curve.arc_length(1000) - curve.arc_length(2000)).abs() < 1e-5);
+    assert_eq!(
+        Bezier::new(vec![vector!(1.0, 2.0)]).derivative(0.5),
+        vector!(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_spline() {
+    let keyframes = vec![
+        vector!(0.0f64, 0.0),
+        vector!(1.0, 0.0),
+        vector!(1.0, 1.0),
+        vector!(0.0, 1.0),
+    ];
+
+    let linear = Spline::new(keyframes.clone(), Interpolation::Linear);
+    assert_eq!(linear.sample(0.5), vector!(1.0, 0.5));
+    assert_eq!(linear.sample(-1.0), vector!(0.0, 0.0));
+    assert_eq!(linear.sample(2.0), vector!(0.0, 1.0));
+    assert!((linear.length(30) - 3.0).abs() < 1e-12);
+    assert!(linear
+        .sample_at_distance(1.5, 30)
+        .approx_eq(&vector!(1.0, 0.5), 1e-12));
+    assert_eq!(linear.sample_at_distance(10.0, 30), vector!(0.0, 1.0));
+
+    let catmull_rom = Spline::new(keyframes.clone(), Interpolation::CatmullRom);
+    for (i, keyframe) in keyframes.iter().enumerate() {
+        let t = i as f64 / 3.0;
+        assert!(catmull_rom.sample(t).approx_eq(keyframe, 1e-12));
+    }
+
+    let b_spline = Spline::new(keyframes, Interpolation::BSpline);
+    assert!(b_spline
+        .sample(0.5)
+        .approx_eq(&vector!(23.0 / 24.0, 0.5), 1e-12));
+    assert!(b_spline.length(1000) < catmull_rom.length(1000));
+
+    let single = Spline::new(vec![vector!(1.0, 2.0)], Interpolation::CatmullRom);
+    assert_eq!(single.sample(0.5), vector!(1.0, 2.0));
+    assert_eq!(single.length(10), 0.0);
+}
+
+#[test]
+fn test_polyline() {
+    let path = Polyline::new(vec![
+        vector!(0.0f64, 0.0),
+        vector!(2.0, 0.0),
+        vector!(2.0, 2.0),
+        vector!(0.0, 2.0),
+    ]);
+    assert_eq!(path.length(), 6.0);
+    assert_eq!(path.point_at_distance(-1.0), vector!(0.0, 0.0));
+    assert_eq!(path.point_at_distance(3.0), vector!(2.0, 1.0));
+    assert_eq!(path.point_at_distance(10.0), vector!(0.0, 2.0));
+
+    assert_eq!(path.closest_point(&vector!(3.0, 1.0)), vector!(2.0, 1.0));
+    assert_eq!(path.closest_point(&vector!(-1.0, -1.0)), vector!(0.0, 0.0));
+    assert_eq!(path.project(&vector!(1.0, 3.0)), 5.0);
+
+    let resampled = path.resample(4);
+    assert_eq!(
+        resampled.points(),
+        &[
+            vector!(0.0, 0.0),
+            vector!(2.0, 0.0),
+            vector!(2.0, 2.0),
+            vector!(0.0, 2.0)
+        ]
+    );
+    assert_eq!(path.resample(7).points()[3], vector!(2.0, 1.0));
+    assert_eq!(path.resample(1).points(), &[vector!(0.0, 0.0)]);
+
+    let point = Polyline::new(vec![vector!(1.0, 1.0)]);
+    assert_eq!(point.length(), 0.0);
+    assert_eq!(point.point_at_distance(1.0), vector!(1.0, 1.0));
+    assert_eq!(point.resample(3).points().len(), 3);
+}
+
+#[test]
+fn test_low_discrepancy() {
+    assert_eq!(Vector::<f64, 3>::halton(0), vector!(0.0, 0.0, 0.0));
+    assert!(Vector::<f64, 3>::halton(3).approx_eq(&vector!(0.75, 1.0 / 9.0, 0.6), 1e-12));
+    assert_eq!(Vector::<f32, 4>::halton(1).as_slice()[3], 1.0 / 7.0);
+    assert_eq!(Vector::<f64, 1>::r_sequence(0), vector!(0.5));
+
+    // Every cell of an 8x8 grid gets exactly one of the first 64 points in base 2,
+    // and the other axis is spread evenly as well
+    let mut cells = [0; 8];
+    for i in 0..64 {
+        let point = Vector::<f64, 2>::halton(i);
+        cells[(point.x() * 8.0) as usize] += 1;
+    }
+    assert_eq!(cells, [8; 8]);
+
+    for generator in [Vector::<f64, 2>::halton, Vector::<f64, 2>::r_sequence] {
+        let points = (1..1000).map(generator).collect::<Vec<_>>();
+        assert!(points
+            .iter()
+            .all(|x| (0.0..1.0).contains(&x.x()) && (0.0..1.0).contains(&x.y())));
+
+        let inside = points.iter().filter(|x| x.magnitude() < 1.0).count();
+        let estimate = 4.0 * inside as f64 / points.len() as f64;
+        assert!((estimate - std::f64::consts::PI).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_dimension() {
+    assert_eq!(Vector::<u8, 2>::DIM, 2);
+    assert_eq!(Vector::<f64, 7>::DIM, 7);
+
+    let a = vector!(1, 2, 3);
+    assert_eq!(a.len(), 3);
+    assert!(!a.is_empty());
+    assert!(Vector::<i32, 0>::new([]).is_empty());
+
+    const LEN: usize = Vector::<f32, 4>::DIM * 2;
+    assert_eq!([0; LEN].len(), 8);
+}
+
+#[test]
+fn test_zeros() {
+    assert!(vector!(0, 0, 0).is_zero());
+    assert!(vector!(0.0, -0.0).is_zero());
+    assert!(!vector!(0, 1, 0).is_zero());
+
+    let a = vector!(0.0, 2.5, 0.0, -1.0);
+    assert_eq!(a.count_zeros(), 2);
+    assert_eq!(a.count_nonzero(), 2);
+    assert_eq!(Vector::<i32, 3>::zero().count_nonzero(), 0);
+    assert_eq!(vector!(f64::NAN, 0.0).count_nonzero(), 1);
+}
+
+#[test]
+fn test_mask_axes() {
+    let a = vector!(1, 2, 3, 4);
+    assert_eq!(
+        a.mask_axes(&vector!(true, false, true, false)),
+        vector!(0, 2, 0, 4)
+    );
+    assert_eq!(a.mask_axes(&Vector::new([false; 4])), a);
+    assert_eq!(a.mask_axes(&Vector::new([true; 4])), Vector::zero());
+    assert_eq!(a.zero_axis(1), vector!(1, 0, 3, 4));
+}
+
+#[test]
+fn test_with_component() {
+    let a = vector!(1.0, 2.0);
+    assert_eq!(a.with_component(0, -1.0), vector!(-1.0, 2.0));
+    assert_eq!(a, vector!(1.0, 2.0));
+
+    let b = (0..4).fold(Vector::<usize, 4>::zero(), |acc, i| {
+        acc.with_component(i, i * i)
+    });
+    assert_eq!(b, vector!(0, 1, 4, 9));
+    assert!(std::panic::catch_unwind(|| a.with_component(2, 0.0)).is_err());
+}
+
+#[test]
+fn test_map() {
+    assert_eq!(vector!(1, 2, 3).map(|x| x * 2), vector!(2, 4, 6));
+    assert_eq!(
+        vector!(1.5f64, -0.5).map(|x| x.to_string()).as_slice(),
+        ["1.5", "-0.5"]
+    );
+
+    let a = vector!(5, 6, 7);
+    assert_eq!(a.map_indexed(|i, x| x * i as i32), vector!(0, 6, 14));
+
+    // Build the row of an identity matrix
+    let row = Vector::<u8, 4>::zero().map_indexed(|i, _| u8::from(i == 2));
+    assert_eq!(row, vector!(0, 0, 1, 0));
+}
+
+#[test]
+fn test_try_map() {
+    let a = vector!(1u32, 200, 3);
+    assert_eq!(a.try_map(u8::try_from), Ok(vector!(1u8, 200, 3)));
+    assert!(vector!(1u32, 300).try_map(u8::try_from).is_err());
+
+    let mut calls = 0;
+    let result = vector!(1, -1, 2).try_map(|x| {
+        calls += 1;
+        if x < 0 {
+            Err(x)
+        } else {
+            Ok(x * 2)
+        }
+    });
+    assert_eq!(result, Err(-1));
+    assert_eq!(calls, 2);
+
+    let owned = vector!("a", "b").try_map(|x| Ok::<_, ()>(x.to_owned()));
+    assert_eq!(owned, Ok(vector!("a".to_owned(), "b".to_owned())));
+}
+
+#[test]
+fn test_zip() {
+    let position = vector!(1.5, -2.0, 0.0);
+    let locked = vector!(false, true, false);
+
+    let moved = position.zip(locked).map(|(x, locked)| {
+        if locked {
+            (x, locked)
+        } else {
+            (x + 1.0, locked)
+        }
+    });
+    let (position, locked) = moved.unzip();
+    assert_eq!(position, vector!(2.5, -2.0, 1.0));
+    assert_eq!(locked, vector!(false, true, false));
+
+    let names = vector!(String::from("a"), String::from("b"));
+    let (numbers, names) = vector!(1, 2).zip(names).unzip();
+    assert_eq!(numbers, vector!(1, 2));
+    assert_eq!(names.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_lanes() {
+    use crate::slice::{from_lanes, to_lanes};
+
+    let points = [vector!(1.0, 2.0, 3.0), vector!(4.0, 5.0, 6.0)];
+    let lanes = to_lanes(&points);
+    assert_eq!(lanes, [vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+    assert_eq!(from_lanes(lanes.each_ref().map(Vec::as_slice)), points);
+    assert!(to_lanes::<i32, 2>(&[]).iter().all(Vec::is_empty));
+
+    let batch = VectorBatch::from_lanes(lanes.clone());
+    assert_eq!(batch.to_vectors(), points);
+    assert_eq!(batch.into_lanes(), lanes);
+
+    assert!(std::panic::catch_unwind(|| from_lanes([&[1, 2][..], &[3]])).is_err());
+    assert!(std::panic::catch_unwind(|| VectorBatch::from_lanes([vec![1], vec![]])).is_err());
+}
+
+#[test]
+fn test_transpose_option() {
+    let a = vector!(4u32, 9, 16);
+    assert_eq!(
+        a.map(|x| x.checked_sub(4)).transpose(),
+        Some(vector!(0, 5, 12))
+    );
+    assert_eq!(a.map(|x| x.checked_sub(5)).transpose(), None);
+
+    let parsed = vector!("1", "x", "y").map(str::parse::<i32>).transpose();
+    assert!(parsed.is_err());
+    assert_eq!(
+        vector!(Ok::<_, ()>(1.5), Ok(2.5)).transpose(),
+        Ok(vector!(1.5, 2.5))
+    );
+}
+
+#[test]
+fn test_reduce() {
+    let a = vector!(3, 1, 4, 1, 5);
+    assert_eq!(a.reduce(|x, y| x + y), 14);
+    assert_eq!(a.reduce(|x, y| x * 10 + y), 31415);
+    assert_eq!(vector!(7).reduce(|_, _| unreachable!()), 7);
+
+    // The L3 norm
+    let b = vector!(1.0f64, 2.0);
+    assert_eq!(
+        b.map(|x| x.powi(3)).reduce(|x, y| x + y).cbrt(),
+        9f64.cbrt()
+    );
+    assert!(std::panic::catch_unwind(|| Vector::<i32, 0>::new([]).reduce(|x, y| x + y)).is_err());
+}
+
+#[test]
+fn test_widening() {
+    let steps = [vector!(i32::MAX, -1), vector!(i32::MAX, i32::MIN)];
+    let total = steps
+        .iter()
+        .try_fold(Vector::<i64, 2>::zero(), |acc, x| acc.widening_add(x))
+        .unwrap();
+    assert_eq!(total, vector!(2 * i32::MAX as i64, i32::MIN as i64 - 1));
+
+    assert_eq!(
+        vector!(1000u64, 0).widening_sub(&vector!(1u8, 0)),
+        Some(vector!(999, 0))
+    );
+    assert_eq!(
+        vector!(3.0f64, 1.5).widening_mul(&vector!(2.0f32, -2.0)),
+        Some(vector!(6.0, -3.0))
+    );
+    assert_eq!(vector!(0u64, 0).widening_add(&vector!(-1i8, 0)), None);
+}
+
+#[test]
+fn test_point_in_polygon() {
+    use crate::geometry::point_in_polygon;
+
+    let square = [vector!(0, 0), vector!(4, 0), vector!(4, 4), vector!(0, 4)];
+    let mut clockwise = square;
+    clockwise.reverse();
+    for polygon in [square, clockwise] {
+        assert!(point_in_polygon(&vector!(2, 2), &polygon));
+        assert!(point_in_polygon(&vector!(0, 0), &polygon));
+        assert!(point_in_polygon(&vector!(4, 2), &polygon));
+        assert!(!point_in_polygon(&vector!(5, 2), &polygon));
+        assert!(!point_in_polygon(&vector!(-1, 4), &polygon));
+        assert!(!point_in_polygon(&vector!(2, 5), &polygon));
+    }
+
+    // A ray through a vertex must not be counted twice
+    let diamond = [
+        vector!(0.0, -1.0),
+        vector!(1.0, 0.0),
+        vector!(0.0, 1.0),
+        vector!(-1.0, 0.0),
+    ];
+    assert!(point_in_polygon(&vector!(0.0, 0.0), &diamond));
+    assert!(!point_in_polygon(&vector!(-2.0, 0.0), &diamond));
+    assert!(!point_in_polygon(&vector!(2.0, 0.0), &diamond));
+    assert!(!point_in_polygon(&vector!(0.9, 0.9), &diamond));
+
+    // Both loops of a figure eight are inside
+    let bowtie = [vector!(0, 0), vector!(2, 2), vector!(2, 0), vector!(0, 2)];
+    assert!(point_in_polygon(&vector!(1, 1), &bowtie));
+    assert!(point_in_polygon(&vector!(1, 2), &bowtie.map(|x| x * 2)));
+    assert!(!point_in_polygon(&vector!(1, 0), &bowtie.map(|x| x * 2)));
+
+    assert!(!point_in_polygon(&vector!(0, 0), &[]));
+}
+
+#[test]
+fn test_polygon_area() {
+    use crate::geometry::{polygon_area, polygon_centroid, polygon_signed_area};
+
+    let triangle = [vector!(0, 0), vector!(4, 0), vector!(0, 3)];
+    assert_eq!(polygon_signed_area(&triangle), 6);
+    assert_eq!(
+        polygon_area(&[vector!(0, 0), vector!(0, 3), vector!(4, 0)]),
+        6
+    );
+    assert_eq!(
+        polygon_signed_area(&[vector!(0, 0), vector!(1, 0), vector!(0, 1)]),
+        0
+    );
+    assert_eq!(polygon_signed_area::<i32>(&[]), 0);
+
+    // Translation doesn't change the area
+    let square = [
+        vector!(0.0, 0.0),
+        vector!(3.0, 0.0),
+        vector!(3.0, 3.0),
+        vector!(0.0, 3.0),
+    ];
+    let moved = square.map(|x| x + vector!(10.0, -5.0));
+    assert_eq!(polygon_area(&moved), 9.0);
+    assert_eq!(polygon_centroid(&square), Some(vector!(1.5, 1.5)));
+    assert_eq!(polygon_centroid(&moved), Some(vector!(11.5, -3.5)));
+
+    // Extra points along an edge don't move the centroid
+    let mut dense = square.to_vec();
+    dense.splice(1..1, (1..30).map(|i| vector!(i as f64 * 0.1, 0.0)));
+    assert!(polygon_centroid(&dense)
+        .unwrap()
+        .approx_eq(&vector!(1.5, 1.5), 1e-12));
+
+    let line = [vector!(0.0, 0.0), vector!(1.0, 1.0), vector!(2.0, 2.0)];
+    assert_eq!(polygon_centroid(&line), None);
+}
+
+#[test]
+fn test_segment_intersection() {
+    use crate::geometry::segment_intersection;
+
+    let (a1, a2) = (vector!(0.0, 0.0), vector!(4.0, 0.0));
+    let hit = |b1, b2| segment_intersection(&a1, &a2, &b1, &b2);
+
+    assert_eq!(
+        hit(vector!(1.0, -1.0), vector!(3.0, 1.0)),
+        Some(vector!(2.0, 0.0))
+    );
+    assert_eq!(
+        hit(vector!(4.0, 0.0), vector!(5.0, 3.0)),
+        Some(vector!(4.0, 0.0))
+    );
+    assert_eq!(
+        hit(vector!(2.0, 0.0), vector!(2.0, 5.0)),
+        Some(vector!(2.0, 0.0))
+    );
+    assert_eq!(hit(vector!(5.0, -1.0), vector!(5.0, 1.0)), None);
+    assert_eq!(hit(vector!(1.0, 1.0), vector!(2.0, 0.5)), None);
+
+    // Parallel
+    assert_eq!(hit(vector!(0.0, 1.0), vector!(4.0, 1.0)), None);
+
+    // Collinear
+    assert_eq!(
+        hit(vector!(3.0, 0.0), vector!(6.0, 0.0)),
+        Some(vector!(3.0, 0.0))
+    );
+    assert_eq!(
+        hit(vector!(6.0, 0.0), vector!(-1.0, 0.0)),
+        Some(vector!(0.0, 0.0))
+    );
+    assert_eq!(
+        hit(vector!(-3.0, 0.0), vector!(0.0, 0.0)),
+        Some(vector!(0.0, 0.0))
+    );
+    assert_eq!(hit(vector!(5.0, 0.0), vector!(6.0, 0.0)), None);
+
+    // Points
+    assert_eq!(
+        hit(vector!(1.0, 0.0), vector!(1.0, 0.0)),
+        Some(vector!(1.0, 0.0))
+    );
+    assert_eq!(hit(vector!(1.0, 1.0), vector!(1.0, 1.0)), None);
+    let point = vector!(2.0, 0.0);
+    assert_eq!(segment_intersection(&point, &point, &a1, &a2), Some(point));
+    assert_eq!(
+        segment_intersection(&point, &point, &point, &point),
+        Some(point)
+    );
+    assert_eq!(
+        segment_intersection(&point, &point, &a2, &vector!(5.0, 0.0)),
+        None
+    );
+}
+
+#[test]
+fn test_covariance() {
+    assert_eq!(Vector::<f64, 3>::covariance([]), None);
+
+    let points = [
+        vector!(1.0f64, 2.0, 0.0),
+        vector!(3.0, 1.0, 0.0),
+        vector!(5.0, 0.0, 0.0),
+        vector!(7.0, 5.0, 0.0),
+    ];
+    let covariance = Vector::covariance(points).unwrap();
+    let expected = Matrix::new([[5.0, 2.0, 0.0], [2.0, 3.5, 0.0], [0.0, 0.0, 0.0]]);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((covariance[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+        }
+    }
+    assert_eq!(covariance.diagonal(), Vector::variance(points).unwrap());
+
+    let single = Vector::covariance([vector!(1.0f32, 2.0)]).unwrap();
+    assert_eq!(single, Matrix::zero());
+}
+
+#[test]
+fn test_principal_axes() {
+    let symmetric = Matrix::new([[4.0f64, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 5.0]]);
+    let (values, vectors) = symmetric.symmetric_eigen();
+    for i in 0..3 {
+        let vector = vectors.column(i);
+        assert!((vector.magnitude() - 1.0).abs() < 1e-12);
+        assert!((symmetric * vector).approx_eq(&(vector * values.as_slice()[i]), 1e-12));
+    }
+    assert!((values.as_slice().iter().sum::<f64>() - symmetric.trace()).abs() < 1e-12);
+
+    // Points spread along a rotated ellipse
+    let rotation = Rotation2::from_angle(Angle::from_radians(0.3f64));
+    let points = (0..360)
+        .map(|i| {
+            let angle = (i as f64).to_radians();
+            rotation.apply(&vector!(3.0 * angle.cos(), angle.sin()))
+        })
+        .collect::<Vec<_>>();
+    let (axes, variances) = Vector::principal_axes(points).unwrap();
+    let major = rotation.apply(&vector!(1.0, 0.0));
+    assert!((axes[0].dot(&major).abs() - 1.0).abs() < 1e-12);
+    assert!(axes[0].dot(&axes[1]).abs() < 1e-12);
+    assert!(variances.approx_eq(&vector!(4.5, 0.5), 1e-12));
+
+    assert_eq!(Vector::<f64, 2>::principal_axes([]), None);
+}
+
+#[test]
+fn test_weighted_mean() {
+    let points = [vector!(1.0, 5.0), vector!(3.0, 1.0), vector!(-2.0, 0.0)];
+    assert_eq!(
+        Vector::weighted_mean(points.map(|x| (x, 1.0))),
+        Vector::centroid(points)
+    );
+    assert_eq!(
+        Vector::weighted_mean([(vector!(10, 20), 2), (vector!(40, 50), 1)]),
+        Some(vector!(20, 30))
+    );
+    assert_eq!(
+        Vector::weighted_mean([(vector!(1.0, 1.0), 0.0), (vector!(5.0, 3.0), 2.0)]),
+        Some(vector!(5.0, 3.0))
+    );
+
+    assert_eq!(Vector::<f64, 2>::weighted_mean([]), None);
+    assert_eq!(Vector::weighted_mean([(vector!(1.0, 1.0), 0.0)]), None);
+}
+
+#[test]
+fn test_softmax() {
+    assert_eq!(
+        vector!(2.0, 0.0, 6.0).normalized_sum(),
+        Some(vector!(0.25, 0.0, 0.75))
+    );
+    assert_eq!(vector!(1.0, -1.0).normalized_sum(), None);
+    assert_eq!(
+        vector!(2.0f32, -1.0).normalized_sum(),
+        Some(vector!(2.0, -1.0))
+    );
+
+    let a = vector!(1.0f64, 2.0, 3.0);
+    let probabilities = a.softmax();
+    let total = 1.0 + 1f64.exp() + 2f64.exp();
+    assert!(probabilities.approx_eq(
+        &vector!(1.0 / total, 1f64.exp() / total, 2f64.exp() / total),
+        1e-12
+    ));
+    assert!((probabilities.as_slice().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    // Shifting every component doesn't change the result
+    assert!((a + 500.0).softmax().approx_eq(&probabilities, 1e-12));
+    assert_eq!(
+        vector!(0.0, 0.0, 0.0, 0.0).softmax(),
+        vector!(0.25, 0.25, 0.25, 0.25)
+    );
+
+    // Infinite maximums would otherwise give NaN from infinity minus infinity
+    assert_eq!(
+        vector!(f64::NEG_INFINITY, f64::NEG_INFINITY).softmax(),
+        vector!(0.5, 0.5)
+    );
+    assert_eq!(
+        vector!(f64::INFINITY, 1.0, f64::INFINITY, f64::NEG_INFINITY).softmax(),
+        vector!(0.5, 0.0, 0.5, 0.0)
+    );
+    assert_eq!(vector!(f32::INFINITY).softmax(), vector!(1.0));
+}
+
+#[test]
+fn test_saturate() {
+    assert_eq!(vector!(0.0, 1.0, 0.5).saturate(), vector!(0.0, 1.0, 0.5));
+    assert_eq!(
+        vector!(f64::NEG_INFINITY, f64::INFINITY).saturate(),
+        vector!(0.0, 1.0)
+    );
+    assert_eq!(vector!(-3, 0, 7).saturate(), vector!(0, 0, 1));
+    assert!(vector!(f32::NAN, 0.0).saturate().x().is_nan());
+}
+
+#[test]
+fn test_mul_add() {
+    assert_eq!(
+        vector!(1, 2, 3).mul_add(3, &vector!(1, 1, -1)),
+        vector!(4, 7, 8)
+    );
+
+    // The fused operation only rounds once
+    let a = vector!(1.0 + f64::EPSILON, 1.0);
+    let b = vector!(-1.0 - 2.0 * f64::EPSILON, 0.0);
+    assert_eq!(
+        a.mul_add(1.0 + f64::EPSILON, &b),
+        vector!(f64::EPSILON * f64::EPSILON, 1.0 + f64::EPSILON)
+    );
+}
+
+#[test]
+fn test_exp_decay() {
+    let (start, target) = (vector!(0.0f64, 4.0), vector!(8.0, 0.0));
+    assert_eq!(start.exp_decay(&target, 3.0, 0.0), start);
+    assert!(start
+        .exp_decay(&target, 1.0, 2f64.ln())
+        .approx_eq(&vector!(4.0, 2.0), 1e-12));
+
+    // Many small steps reach the same point as one large step
+    let mut position = start;
+    for _ in 0..60 {
+        position = position.exp_decay(&target, 5.0, 1.0 / 60.0);
+    }
+    assert!(position.approx_eq(&start.exp_decay(&target, 5.0, 1.0), 1e-12));
+    assert!(start
+        .exp_decay(&target, 5.0, 100.0)
+        .approx_eq(&target, 1e-12));
+}
+
+#[test]
+fn test_angle() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    let right = Angle::from_degrees(90.0);
+    assert_eq!(right, Angle::from_radians(FRAC_PI_2));
+    assert_eq!((right + right).degrees(), 180.0);
+    assert_eq!((right - right * 3.0).radians(), -PI);
+    assert_eq!((-right / 2.0).degrees(), -45.0);
+    assert!(right < right * 2.0);
+    assert_eq!(right.sin(), 1.0);
+
+    assert_eq!(Angle::from_degrees(-180.0).wrapped().radians(), PI);
+    assert!((Angle::from_degrees(450.0f64).wrapped().degrees() - 90.0).abs() < 1e-12);
+    assert_eq!(format!("{:?}", Angle::from_radians(1.5)), "1.5 rad");
+
+    let a = vector!(1.0, 0.0, 0.0);
+    assert_eq!(
+        a.angle_between(&vector!(0.0, 3.0, 0.0)).radians(),
+        FRAC_PI_2
+    );
+    assert_eq!(a.angle_between(&vector!(-2.0, 0.0, 0.0)).radians(), PI);
+    assert_eq!(a.angle_between(&a).radians(), 0.0);
+    assert_eq!(a.angle_between(&Vector::zero()).radians(), 0.0);
+    assert!((a.angle_between(&vector!(1.0, 1e-10, 0.0)).radians() - 1e-10).abs() < 1e-20);
+
+    assert!(vector!(1.0, 1.0)
+        .rotate(Angle::from_degrees(-90.0))
+        .approx_eq(&vector!(1.0, -1.0), 1e-12));
+}
+
+#[test]
+fn test_look_at() {
+    let heading = Rotation2::look_at(&vector!(2.0, 2.0), &vector!(0.0, 0.0));
+    assert_eq!(heading.angle(), Angle::from_degrees(-135.0));
+    assert_eq!(
+        Rotation2::look_at(&vector!(1.0, 1.0), &vector!(1.0, 1.0)),
+        Rotation2::identity()
+    );
+    let pose = Isometry2::look_at(&vector!(1.0, 0.0), &vector!(1.0, 5.0));
+    assert!(pose
+        .transform_point(&vector!(2.0, 0.0))
+        .approx_eq(&vector!(1.0, 2.0), 1e-12));
+
+    let (eye, target, up) = (
+        vector!(1.0, 2.0, 3.0),
+        vector!(4.0, -2.0, 3.0),
+        vector!(0.0, 1.0, 0.0),
+    );
+    let camera = Rotation3::look_at(&eye, &target, &up).unwrap();
+    let matrix = camera.matrix();
+    assert!((matrix * matrix.transpose() - Matrix::identity())
+        .rows
+        .iter()
+        .flatten()
+        .all(|x: &f64| x.abs() < 1e-12));
+    assert!((matrix.determinant() - 1.0).abs() < 1e-12);
+    assert!(camera
+        .apply(&vector!(0.0, 0.0, -1.0))
+        .approx_eq(&vector!(0.6, -0.8, 0.0), 1e-12));
+    assert!(camera.apply(&vector!(0.0, 1.0, 0.0)).dot(&up) > 0.0);
+    assert!(camera.apply(&vector!(1.0, 0.0, 0.0)).dot(&up).abs() < 1e-12);
+
+    assert!(Rotation3::look_at(&eye, &eye, &up).is_none());
+    assert!(Rotation3::look_at(&eye, &(eye + up), &up).is_none());
+    assert!(Rotation3::align_axes(&up, &Vector::zero(), &up, &up).is_none());
+
+    let barrel = vector!(1.0, 0.0, 0.0);
+    let aim = Rotation3::align_axes(&barrel, &up, &vector!(0.0, 0.0, 3.0), &up).unwrap();
+    assert!(aim.apply(&barrel).approx_eq(&vector!(0.0, 0.0, 1.0), 1e-12));
+    assert!(aim.apply(&up).approx_eq(&up, 1e-12));
+
+    let view = Matrix::look_at(&eye, &target, &up).unwrap();
+    let pose = Isometry3::look_at(&eye, &target, &up).unwrap();
+    let [x, y, z] = pose.transform_point(&vector!(0.5, -1.0, -2.0)).components;
+    assert!((view * vector!(x, y, z, 1.0)).approx_eq(&vector!(0.5, -1.0, -2.0, 1.0), 1e-12));
+}
+
+#[test]
+fn test_reflect_across() {
+    let normal = vector!(1.0, 1.0, 0.0);
+    let plane_point = vector!(1.0, 0.0, 0.0);
+    let point = vector!(3.0, 2.0, -1.0);
+    let mirrored = point.reflect_across_plane(&normal, &plane_point);
+    assert_eq!(mirrored, vector!(-1.0, -2.0, -1.0));
+    assert_eq!(mirrored.reflect_across_plane(&normal, &plane_point), point);
+    assert_eq!(
+        plane_point.reflect_across_plane(&normal, &plane_point),
+        plane_point
+    );
+
+    let line_point = vector!(0.0, 2.0);
+    let direction = vector!(4.0, 0.0);
+    assert_eq!(
+        vector!(5.0, 7.0).reflect_across_line(&direction, &line_point),
+        vector!(5.0, -3.0)
+    );
+    assert_eq!(
+        vector!(5.0, 7.0).reflect_across_line(&direction, &line_point),
+        vector!(5.0, 7.0).reflect_across_plane(&vector!(0.0, 1.0), &line_point)
+    );
+    assert_eq!(
+        vector!(-3.0, 2.0).reflect_across_line(&direction, &line_point),
+        vector!(-3.0, 2.0)
+    );
+}
+
+#[test]
+fn test_format_traits() {
+    let a = vector!(1500.0, 0.00025);
+    assert_eq!(format!("{:e}", a), "(1.5e3, 2.5e-4)");
+    assert_eq!(format!("{:.2E}", a), "(1.50E3, 2.50E-4)");
+
+    let b = vector!(5u8, 255, 8);
+    assert_eq!(format!("{:b}", b), "(101, 11111111, 1000)");
+    assert_eq!(
+        format!("{:#010b}", b),
+        "(0b00000101, 0b11111111, 0b00001000)"
+    );
+    assert_eq!(format!("{:o}", b), "(5, 377, 10)");
+    assert_eq!(format!("{:x}", b), "(5, ff, 8)");
+    assert_eq!(format!("{:#04X}", b), "(0x05, 0xFF, 0x08)");
+    assert_eq!(format!("{:x}", Vector::<u8, 0>::new([])), "()");
+}
+
+#[test]
+fn test_pretty_output() {
+    let a = vector!(0.5, -12.125, 3.0);
+    assert_eq!(
+        a.to_latex(),
+        r"\begin{pmatrix} 0.5 \\ -12.125 \\ 3 \end{pmatrix}"
+    );
+    assert_eq!(
+        a.to_column_string(),
+        "[   0.5   ]\n[ -12.125 ]\n[   3     ]"
+    );
+
+    assert_eq!(vector!(7, 100).to_column_string(), "[   7 ]\n[ 100 ]");
+    assert_eq!(Vector::<i32, 0>::new([]).to_column_string(), "");
+    assert_eq!(
+        Vector::<i32, 0>::new([]).to_latex(),
+        r"\begin{pmatrix}  \end{pmatrix}"
+    );
+}
@@ -0,0 +1,151 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::{geometry::barycentric, Vector};
+
+/// A triangle in N-dimensional space, defined by its three vertices.
+///
+/// ```rust
+/// # use nd_vec::{Triangle, vector};
+/// let triangle = Triangle::new(vector!(0.0, 0.0), vector!(4.0, 0.0), vector!(0.0, 4.0));
+/// assert_eq!(triangle.area(), 8.0);
+/// assert!(triangle.contains_point(&vector!(1.0, 1.0)));
+/// assert_eq!(triangle.closest_point(&vector!(4.0, 4.0)), vector!(2.0, 2.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Hash)]
+pub struct Triangle<T, const N: usize> {
+    /// The first vertex.
+    pub a: Vector<T, N>,
+    /// The second vertex.
+    pub b: Vector<T, N>,
+    /// The third vertex.
+    pub c: Vector<T, N>,
+}
+
+impl<T, const N: usize> Triangle<T, N> {
+    /// Creates a new triangle from its vertices.
+    pub const fn new(a: Vector<T, N>, b: Vector<T, N>, c: Vector<T, N>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Triangle<T, N> {
+    /// Returns the vertices of the triangle.
+    pub fn vertices(&self) -> [Vector<T, N>; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Calculates the centroid of the triangle, the mean of its vertices.
+    pub fn centroid(&self) -> Vector<T, N> {
+        let three = T::one() + T::one() + T::one();
+        (self.a + self.b + self.c) / three
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> Triangle<T, N> {
+    /// Calculates the area of the triangle.
+    pub fn area(&self) -> T {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let dot = ab.dot(&ac);
+        let squared = ab.magnitude_squared() * ac.magnitude_squared() - dot * dot;
+        squared.max(T::zero()).sqrt() / (T::one() + T::one())
+    }
+
+    /// Calculates the [barycentric coordinates](crate::geometry::barycentric) of a point with respect to the triangle.
+    pub fn barycentric(&self, point: &Vector<T, N>) -> Vector<T, 3> {
+        barycentric(point, &self.a, &self.b, &self.c)
+    }
+
+    /// Checks if a point is inside the triangle or on its edges.
+    /// In three or more dimensions the point must also lie in the plane of the triangle, up to a distance of `sqrt(epsilon)` times the longest edge to allow for rounding.
+    pub fn contains_point(&self, point: &Vector<T, N>) -> bool {
+        let weights = self.barycentric(point);
+        if !weights.components.iter().all(|&x| x >= T::zero()) {
+            return false;
+        }
+
+        // The weights describe the projection of the point onto the plane, so check how far away the point is from it
+        let [u, v, w] = weights.components;
+        let projected = scale(self.a, u) + scale(self.b, v) + scale(self.c, w);
+        let longest = [self.b - self.a, self.c - self.a, self.c - self.b]
+            .iter()
+            .map(|edge| edge.magnitude_squared())
+            .fold(T::zero(), T::max);
+        (*point - projected).magnitude_squared() <= T::epsilon() * longest
+    }
+
+    /// Finds the point on the triangle, including its interior, that is closest to `point`.
+    /// Uses the Voronoi region method from Christer Ericson's *Real-Time Collision Detection*.
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        let (a, b, c, p) = (self.a, self.b, self.c, *point);
+        let (ab, ac, ap) = (b - a, c - a, p - a);
+
+        let (d1, d2) = (ab.dot(&ap), ac.dot(&ap));
+        if d1 <= T::zero() && d2 <= T::zero() {
+            return a;
+        }
+
+        let bp = p - b;
+        let (d3, d4) = (ab.dot(&bp), ac.dot(&bp));
+        if d3 >= T::zero() && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::zero() && d1 >= T::zero() && d3 <= T::zero() {
+            return a + scale(ab, d1 / (d1 - d3));
+        }
+
+        let cp = p - c;
+        let (d5, d6) = (ab.dot(&cp), ac.dot(&cp));
+        if d6 >= T::zero() && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::zero() && d2 >= T::zero() && d6 <= T::zero() {
+            return a + scale(ac, d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::zero() && d4 - d3 >= T::zero() && d5 - d6 >= T::zero() {
+            return b + scale(c - b, (d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = va + vb + vc;
+        a + scale(ab, vb / denominator) + scale(ac, vc / denominator)
+    }
+}
+
+/// Multiplies a vector by a scalar, without the `Send + Sync` bound of the `Mul` implementation.
+fn scale<T: Num + Copy, const N: usize>(vector: Vector<T, N>, scalar: T) -> Vector<T, N> {
+    Vector::new(vector.components.map(|x| x * scalar))
+}
+
+impl<T: Num + Copy + Sum + Real> Triangle<T, 3> {
+    /// Calculates the unit normal of the triangle.
+    /// The normal points towards the side from which the vertices appear in counter-clockwise order.
+    pub fn normal(&self) -> Vector<T, 3> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let normal = Vector::new([
+            ab.y() * ac.z() - ab.z() * ac.y(),
+            ab.z() * ac.x() - ab.x() * ac.z(),
+            ab.x() * ac.y() - ab.y() * ac.x(),
+        ]);
+        normal.normalize()
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Triangle<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Triangle")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("c", &self.c)
+            .finish()
+    }
+}
@@ -1,16 +1,43 @@
 use std::{
-    fmt::{Debug, Display},
+    cmp::Ordering,
+    fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
     hash::Hash,
     iter::Sum,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Rem, RemAssign, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
 };
 
-use num_traits::{real::Real, Num, NumCast, Signed, ToPrimitive};
+use num_traits::{
+    ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
+    real::Real,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Euclid, Float, FromBytes, MulAdd,
+    Num, NumCast, PrimInt, SaturatingAdd, SaturatingMul, SaturatingSub, Signed, ToBytes,
+    ToPrimitive, WrappingAdd, WrappingMul, WrappingSub,
+};
+
+use crate::{Angle, Matrix, Rotation2, WrongLength};
 
 /// A compile-time n-dimensional vector, how fancy!
+///
+/// # Layout
+/// Vectors are `#[repr(C)]` and are guaranteed to have the same layout as `[T; N]`, so they can be safely transmuted or uploaded to GPU buffers.
+/// Use [`Aligned16`](crate::Aligned16) if a 16-byte alignment is required, for example for `Vector<f32, 4>` in a uniform buffer.
+/// With the `zerocopy` feature, vectors of primitive components implement zerocopy's `FromBytes`, `IntoBytes`, `Immutable`, and `KnownLayout` traits.
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(C)]
 pub struct Vector<T, const N: usize> {
-    components: [T; N],
+    pub(crate) components: [T; N],
 }
 
 /// Create a new vector with the given components.
@@ -23,6 +50,13 @@ pub struct Vector<T, const N: usize> {
 /// # use nd_vec::Vector;
 /// Vector::new([1, 2, 3]);
 /// ````
+///
+/// Because the components are expanded into an array literal, the dimension of the vector is always the number of arguments.
+/// Using the wrong number of components for the expected type is a compile error rather than a silently resized vector.
+/// ```compile_fail
+/// # use nd_vec::{Vector, vector};
+/// let v: Vector<i32, 3> = vector!(1, 2);
+/// ```
 #[macro_export]
 macro_rules! vector {
     ($($x:expr),*) => {
@@ -30,7 +64,43 @@ macro_rules! vector {
     };
 }
 
+/// Combines each pair of components from two arrays with the given binary operator method.
+/// With the `strict` feature integer operations are checked, see [`crate::strict`].
+///
+/// This is a macro rather than a function taking a closure so that, when used in a `#[track_caller]` function, overflow panics point to the caller.
+macro_rules! zip_components {
+    ($a:expr, $b:expr, $func:ident) => {{
+        let (a, b) = ($a, $b);
+        #[cfg(not(feature = "strict"))]
+        let special = None;
+        #[cfg(feature = "strict")]
+        let special = $crate::strict::zip(a, b, zip_components!(@op $func));
+        match special {
+            Some(out) => out,
+            None => {
+                let mut out = *a;
+                for (x, y) in out.iter_mut().zip(b.iter()) {
+                    *x = (*x).$func(*y);
+                }
+                out
+            }
+        }
+    }};
+    (@op add) => { $crate::dispatch::Op::Add };
+    (@op sub) => { $crate::dispatch::Op::Sub };
+    (@op mul) => { $crate::dispatch::Op::Mul };
+    (@op div) => { $crate::dispatch::Op::Div };
+    (@op rem) => { $crate::dispatch::Op::Rem };
+}
+
 impl<T, const N: usize> Vector<T, N> {
+    /// The number of components in the vector.
+    /// ```rust
+    /// # use nd_vec::Vector;
+    /// assert_eq!(Vector::<f32, 3>::DIM, 3);
+    /// ```
+    pub const DIM: usize = N;
+
     /// Create a new vector with the given components.
     /// ```rust
     /// # use nd_vec::Vector;
@@ -50,13 +120,269 @@ impl<T, const N: usize> Vector<T, N> {
         }
     }
 
+    /// Returns the number of components in the vector, the same as [`Vector::DIM`].
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the vector has no components, which is only the case for `Vector<T, 0>`.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
     /// Returns the components of the vector as a slice.
     pub fn as_slice(&self) -> &[T] {
         &self.components
     }
+
+    /// Applies a function to each component, creating a new vector from the results.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1, -2, 3).map(|x| x > 0), vector!(true, false, true));
+    /// ```
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Vector<U, N> {
+        Vector::new(self.components.map(f))
+    }
+
+    /// Applies a function to each component along with its index, creating a new vector from the results.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// // Weight later axes more heavily
+    /// let weights = Vector::<f32, 3>::zero().map_indexed(|i, _| (i + 1) as f32);
+    /// assert_eq!(weights, vector!(1.0, 2.0, 3.0));
+    /// ```
+    pub fn map_indexed<U>(self, mut f: impl FnMut(usize, T) -> U) -> Vector<U, N> {
+        let mut index = 0;
+        Vector::new(self.components.map(|x| {
+            index += 1;
+            f(index - 1, x)
+        }))
+    }
+
+    /// Applies a fallible function to each component, creating a new vector from the results.
+    /// Stops at the first error and returns it.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let parsed = vector!("1", "-2", "3").try_map(str::parse::<i32>);
+    /// assert_eq!(parsed, Ok(vector!(1, -2, 3)));
+    /// assert!(vector!("1", "two").try_map(str::parse::<i32>).is_err());
+    /// ```
+    pub fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<Vector<U, N>, E> {
+        // Components after the first error are skipped rather than passed to `f`
+        let mut error = None;
+        let components = self.components.map(|x| match error {
+            Some(_) => None,
+            None => f(x).map_err(|e| error = Some(e)).ok(),
+        });
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(Vector::new(components.map(Option::unwrap))),
+        }
+    }
+
+    /// Pairs up the components of two vectors into a vector of tuples.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let a = vector!(1, 2).zip(vector!(true, false));
+    /// assert_eq!(a.as_slice(), [(1, true), (2, false)]);
+    /// assert_eq!(a.unzip(), (vector!(1, 2), vector!(true, false)));
+    /// ```
+    pub fn zip<U>(self, other: Vector<U, N>) -> Vector<(T, U), N> {
+        let mut other = other.components.into_iter();
+        self.map(|x| (x, other.next().unwrap()))
+    }
+
+    /// Combines all components into one value by repeatedly applying `f`, from the first component to the last.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // The infinity norm
+    /// assert_eq!(vector!(1.0f64, -4.0, 2.0).map(f64::abs).reduce(f64::max), 4.0);
+    /// ```
+    ///
+    /// # Panics
+    /// If the vector has no components.
+    pub fn reduce(self, f: impl FnMut(T, T) -> T) -> T {
+        self.components
+            .into_iter()
+            .reduce(f)
+            .expect("can't reduce a vector with no components")
+    }
+
+    /// Create a new vector from an iterator that must yield exactly N items.
+    /// Unlike the [`FromIterator`] implementation, an error is returned if there are too few or too many items.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// assert_eq!(Vector::try_from_iter([1, 2, 3]), Ok(vector!(1, 2, 3)));
+    /// assert!(Vector::<i32, 3>::try_from_iter([1, 2]).is_err());
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, WrongLength> {
+        let mut iter = iter.into_iter().fuse();
+        let items = Vector::new([(); N].map(|_| iter.next()));
+        let found = items.components.iter().filter(|x| x.is_some()).count();
+
+        // At most one item past the end is pulled, to check that there are no more
+        match (items.transpose(), iter.next()) {
+            (Some(vector), None) => Ok(vector),
+            (Some(_), Some(_)) => Err(WrongLength {
+                expected: N,
+                found: N + 1,
+            }),
+            (None, _) => Err(WrongLength { expected: N, found }),
+        }
+    }
+}
+
+macro_rules! const_ops {
+    ($($type:ty),*) => {$(
+        impl<const N: usize> Vector<$type, N> {
+            /// Creates a new vector with all components set to the given value.
+            /// Usable in constant contexts.
+            pub const fn const_splat(value: $type) -> Self {
+                Self::new([value; N])
+            }
+
+            /// Component-wise addition of two vectors, usable in constant contexts.
+            pub const fn const_add(self, other: Self) -> Self {
+                let mut components = self.components;
+                let mut i = 0;
+                while i < N {
+                    components[i] += other.components[i];
+                    i += 1;
+                }
+                Self { components }
+            }
+
+            /// Component-wise subtraction of two vectors, usable in constant contexts.
+            pub const fn const_sub(self, other: Self) -> Self {
+                let mut components = self.components;
+                let mut i = 0;
+                while i < N {
+                    components[i] -= other.components[i];
+                    i += 1;
+                }
+                Self { components }
+            }
+
+            /// Component-wise multiplication of two vectors, usable in constant contexts.
+            pub const fn const_mul(self, other: Self) -> Self {
+                let mut components = self.components;
+                let mut i = 0;
+                while i < N {
+                    components[i] *= other.components[i];
+                    i += 1;
+                }
+                Self { components }
+            }
+
+            /// Multiplies each component by a scalar, usable in constant contexts.
+            pub const fn const_mul_scalar(self, other: $type) -> Self {
+                self.const_mul(Self::const_splat(other))
+            }
+        }
+    )*};
+}
+
+macro_rules! const_neg {
+    ($($type:ty),*) => {$(
+        impl<const N: usize> Vector<$type, N> {
+            /// Negates all components of a vector, usable in constant contexts.
+            pub const fn const_neg(self) -> Self {
+                let mut components = self.components;
+                let mut i = 0;
+                while i < N {
+                    components[i] = -components[i];
+                    i += 1;
+                }
+                Self { components }
+            }
+        }
+    )*};
+}
+
+const_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+const_neg!(i8, i16, i32, i64, i128, isize);
+
+impl<T: ToBytes, const N: usize> Vector<T, N> {
+    /// Encodes each component as little-endian bytes.
+    /// The result can be flattened into a single byte slice with [`as_flattened`](https://doc.rust-lang.org/std/primitive.slice.html#method.as_flattened).
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let bytes = vector!(1u16, 0x0203).to_le_bytes();
+    /// assert_eq!(bytes.as_flattened(), [1, 0, 3, 2]);
+    /// ```
+    pub fn to_le_bytes(&self) -> [T::Bytes; N] {
+        self.components.each_ref().map(T::to_le_bytes)
+    }
+
+    /// Encodes each component as big-endian bytes.
+    pub fn to_be_bytes(&self) -> [T::Bytes; N] {
+        self.components.each_ref().map(T::to_be_bytes)
+    }
+}
+
+impl<T: FromBytes, const N: usize> Vector<T, N>
+where
+    T::Bytes: Sized,
+{
+    /// Decodes a vector from the little-endian bytes of each component.
+    /// This is the inverse of [`Vector::to_le_bytes`].
+    pub fn from_le_bytes(bytes: &[T::Bytes; N]) -> Self {
+        Self::new(bytes.each_ref().map(T::from_le_bytes))
+    }
+
+    /// Decodes a vector from the big-endian bytes of each component.
+    /// This is the inverse of [`Vector::to_be_bytes`].
+    pub fn from_be_bytes(bytes: &[T::Bytes; N]) -> Self {
+        Self::new(bytes.each_ref().map(T::from_be_bytes))
+    }
 }
 
 impl<T: Copy, const N: usize> Vector<T, N> {
+    /// Reorders the components of a vector, so component `i` of the result is component `indices[i]` of this vector.
+    /// Indices may repeat, which duplicates components.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // Convert from Y-up to Z-up
+    /// assert_eq!(vector!(1, 2, 3).permute([0, 2, 1]), vector!(1, 3, 2));
+    /// ```
+    ///
+    /// # Panics
+    /// If any index is not less than N.
+    pub fn permute(&self, indices: [usize; N]) -> Self {
+        Self::new(indices.map(|i| self.components[i]))
+    }
+
+    /// Returns a vector with the components in reverse order.
+    pub fn reversed(&self) -> Self {
+        let mut components = self.components;
+        components.reverse();
+        Self { components }
+    }
+
+    /// Returns a copy of the vector with the component at `index` replaced by `value`.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1, 2, 3).with_component(1, 5), vector!(1, 5, 3));
+    /// ```
+    ///
+    /// # Panics
+    /// If `index` is not less than N.
+    pub fn with_component(&self, index: usize, value: T) -> Self {
+        let mut out = *self;
+        out.components[index] = value;
+        out
+    }
+
     /// Allows numerically casting each component of the vector.
     /// Makes use of the [num_traits::NumCast](https://docs.rs/num-traits/0.2.14/num_traits/cast/trait.NumCast.html) trait.
     /// If the cast fails, None is returned.
@@ -115,6 +441,36 @@ impl<T: Copy, const N: usize> Vector<T, N> {
     }
 }
 
+impl<T, U, const N: usize> Vector<(T, U), N> {
+    /// Splits a vector of tuples into a vector of the first items and a vector of the second items.
+    /// This is the inverse of [`Vector::zip`].
+    pub fn unzip(self) -> (Vector<T, N>, Vector<U, N>) {
+        let mut pairs = self.components.map(|(a, b)| (a, Some(b)));
+        let second = std::array::from_fn(|i| pairs[i].1.take().unwrap());
+        (Vector::new(pairs.map(|(a, _)| a)), Vector::new(second))
+    }
+}
+
+impl<T, const N: usize> Vector<Option<T>, N> {
+    /// Converts a vector of options into an optional vector, which is None if any component is None.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(Some(1), Some(2)).transpose(), Some(vector!(1, 2)));
+    /// assert_eq!(vector!(Some(1), None).transpose(), None);
+    /// ```
+    pub fn transpose(self) -> Option<Vector<T, N>> {
+        self.try_map(|x| x.ok_or(())).ok()
+    }
+}
+
+impl<T, E, const N: usize> Vector<Result<T, E>, N> {
+    /// Converts a vector of results into a result of a vector, which is the first error if any component is an error.
+    pub fn transpose(self) -> Result<Vector<T, N>, E> {
+        self.try_map(|x| x)
+    }
+}
+
 impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
     /// Create a new vector with zeroed components.
     fn default() -> Self {
@@ -126,139 +482,1307 @@ impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
 
 impl<T: Num + Copy, const N: usize> Vector<T, N> {
     /// Computes the Hadamard product of two vectors (component-wise multiplication).
+    #[track_caller]
     pub fn hadamard_product(&self, other: &Self) -> Self {
-        let mut components = [T::zero(); N];
-        for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i] * other.components[i];
-        }
+        let components = zip_components!(&self.components, &other.components, mul);
         Self { components }
     }
-}
 
-impl<T: Num + Copy + Ord, const N: usize> Vector<T, N> {
-    /// Takes the minimum of each component of two vectors.
-    pub fn min(&self, other: &Self) -> Self {
-        let mut components = [T::zero(); N];
-        for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i].min(other.components[i]);
-        }
-        Self { components }
+    /// Checks if every component is zero.
+    pub fn is_zero(&self) -> bool {
+        self.components.iter().all(|x| x.is_zero())
     }
 
-    /// Takes the maximum of each component of two vectors.
-    pub fn max(&self, other: &Self) -> Self {
-        let mut components = [T::zero(); N];
-        for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i].max(other.components[i]);
-        }
-        Self { components }
+    /// Counts the components that are zero.
+    pub fn count_zeros(&self) -> usize {
+        self.components.iter().filter(|x| x.is_zero()).count()
     }
 
-    /// Takes the minimum component of a vector.
-    pub fn min_component(&self) -> T {
-        self.components.iter().min().copied().unwrap()
+    /// Counts the components that are not zero.
+    /// A vector with exactly one nonzero component is axis-aligned.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let step = vector!(0, -3, 0);
+    /// assert_eq!(step.count_nonzero(), 1);
+    /// assert_eq!(step.count_zeros(), 2);
+    /// assert!(!step.is_zero());
+    /// ```
+    pub fn count_nonzero(&self) -> usize {
+        N - self.count_zeros()
     }
 
-    /// Takes the maximum component of a vector.
-    pub fn max_component(&self) -> T {
-        self.components.iter().max().copied().unwrap()
+    /// Returns a copy of the vector with the components selected by `mask` set to zero.
+    /// This constrains a movement to the plane or axis of the unselected components.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // Keep a movement on the ground plane
+    /// let velocity = vector!(3.0, -9.8, 1.0);
+    /// assert_eq!(velocity.mask_axes(&vector!(false, true, false)), vector!(3.0, 0.0, 1.0));
+    /// ```
+    pub fn mask_axes(&self, mask: &Vector<bool, N>) -> Self {
+        mask.select(&Self::zero(), self)
+    }
+
+    /// Returns a copy of the vector with the component at `axis` set to zero.
+    ///
+    /// # Panics
+    /// If `axis` is not less than N.
+    pub fn zero_axis(&self, axis: usize) -> Self {
+        let mut out = *self;
+        out.components[axis] = T::zero();
+        out
     }
 }
 
-impl<T: Num + Copy + Signed, const N: usize> Vector<T, N> {
-    /// Calculates the opposite of a vector.
-    /// This is the vector with all components negated.
-    pub fn opposite(&self) -> Self {
+impl<T: Num + Copy + PartialOrd, const N: usize> Vector<T, N> {
+    /// Compares each component against the matching component of `edge`, following the semantics of GLSL's `step`.
+    /// Components less than the edge become zero, and all others become one.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(0.2, 0.5, 0.9).step(&vector!(0.5, 0.5, 0.5)), vector!(0.0, 1.0, 1.0));
+    /// ```
+    pub fn step(&self, edge: &Self) -> Self {
         let mut components = [T::zero(); N];
         for (i, e) in components.iter_mut().enumerate() {
-            *e = -self.components[i];
+            if self.components[i] >= edge.components[i] {
+                *e = T::one();
+            }
         }
         Self { components }
     }
 
-    /// Calculates the sign of each component of a vector.
-    /// This is -1 if the component is negative, 0 if it is zero, and 1 if it is positive.
-    pub fn signum(&self) -> Self {
-        let mut components = [T::zero(); N];
-        for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i].signum();
-        }
-        Self { components }
+    /// Compares each component against a scalar edge, following the semantics of GLSL's `step`.
+    pub fn step_scalar(&self, edge: T) -> Self {
+        self.step(&Self::new([edge; N]))
     }
 
-    /// Calculates the [Manhattan Distance](https://en.wikipedia.org/wiki/Taxicab_geometry#Formal_definition) of two vectors.
-    pub fn manhattan_distance(&self, other: &Self) -> T {
-        let mut out = T::zero();
-        for (&a, &b) in self.components.iter().zip(other.components.iter()) {
-            out = out + (a - b).abs();
-        }
-        out
+    /// Returns a mask of which components are greater than the matching component of `other`.
+    pub fn greater_than(&self, other: &Self) -> Vector<bool, N> {
+        Vector::new(std::array::from_fn(|i| {
+            self.components[i] > other.components[i]
+        }))
     }
-}
 
-impl<T: Num + Copy + Sum, const N: usize> Vector<T, N> {
-    pub fn sum(&self) -> T {
-        let mut acc = T::zero();
-        for i in self.components {
-            acc = acc + i;
-        }
-        acc
+    /// Returns a mask of which components are greater than a scalar.
+    /// Use [`Vector::select`] to pick components based on the mask.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let a = vector!(-2, 5, 1);
+    /// assert_eq!(a.greater_than_scalar(0).select(&a, &vector!(0, 0, 0)), vector!(0, 5, 1));
+    /// ```
+    pub fn greater_than_scalar(&self, other: T) -> Vector<bool, N> {
+        self.greater_than(&Self::new([other; N]))
     }
 
-    /// Calculates the sum of all squared components.
-    /// Used for calculating the magnitude of a vector.
-    pub fn magnitude_squared(&self) -> T {
-        self.components.into_iter().map(|x| x * x).sum()
+    /// Clamps every component to the range [0, 1], like HLSL's `saturate`.
+    /// Useful for colors and interpolation factors.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(-0.5, 0.25, 1.5).saturate(), vector!(0.0, 0.25, 1.0));
+    /// ```
+    pub fn saturate(&self) -> Self {
+        Self::new(self.components.map(|x| {
+            if x < T::zero() {
+                T::zero()
+            } else if x > T::one() {
+                T::one()
+            } else {
+                x
+            }
+        }))
     }
 
-    /// Calculates the dot product of two vectors.
-    pub fn dot(&self, other: &Self) -> T {
-        self.components
+    /// Iterates over every lattice point in the box between `min` and `max` (both inclusive).
+    /// The first component changes the fastest.
+    /// If any component of `min` is greater than the same component of `max`, the iterator is empty.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let points = Vector::iter_box(vector!(0, 0), vector!(1, 1)).collect::<Vec<_>>();
+    /// assert_eq!(points, [vector!(0, 0), vector!(1, 0), vector!(0, 1), vector!(1, 1)]);
+    /// ```
+    pub fn iter_box(min: Self, max: Self) -> impl Iterator<Item = Self> {
+        let start = min
+            .components
             .iter()
-            .zip(other.components.iter())
-            .map(|(a, b)| *a * *b)
-            .sum()
-    }
-}
+            .zip(max.components.iter())
+            .all(|(a, b)| a <= b)
+            .then_some(min);
 
-impl<T: Num + Copy + Sum + Real, const N: usize> Vector<T, N> {
-    /// Calculates the magnitude of a vector.
-    /// This is the square root of the sum of all squared components.
-    pub fn magnitude(&self) -> T {
-        self.magnitude_squared().sqrt()
+        std::iter::successors(start, move |pos| {
+            let mut next = *pos;
+            for i in 0..N {
+                if next.components[i] < max.components[i] {
+                    next.components[i] = next.components[i] + T::one();
+                    return Some(next);
+                }
+                next.components[i] = min.components[i];
+            }
+            None
+        })
     }
 
-    /// Normalizes a vector.
-    /// This is the vector divided by its magnitude.
-    pub fn normalize(&self) -> Self {
-        *self / self.magnitude()
+    /// Calculates the component-wise minimum and maximum over a set of vectors.
+    /// This gives the corners of the smallest axis-aligned box containing every vector.
+    /// Returns None if there are no vectors.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let points = [vector!(1, 5), vector!(-2, 3), vector!(4, 0)];
+    /// assert_eq!(Vector::extents(points), Some((vector!(-2, 0), vector!(4, 5))));
+    /// ```
+    pub fn extents(iter: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        let (mut min, mut max) = (first, first);
+
+        for e in iter {
+            for (i, &x) in e.components.iter().enumerate() {
+                if x < min.components[i] {
+                    min.components[i] = x;
+                }
+                if x > max.components[i] {
+                    max.components[i] = x;
+                }
+            }
+        }
+
+        Some((min, max))
     }
+}
 
-    /// Calculates the [Euclidean Distance](https://en.wikipedia.org/wiki/Euclidean_distance) of two vectors.
-    pub fn distance(&self, other: &Self) -> T {
-        (*self - *other).magnitude()
+impl<T: PrimInt, const N: usize> Vector<T, N> {
+    /// Divides all components by their greatest common divisor.
+    /// This gives the smallest integer vector pointing in the same direction, for example (4, -6) becomes (2, -3).
+    /// The zero vector is returned unchanged.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(4, -6).reduced(), vector!(2, -3));
+    /// assert_eq!(vector!(i32::MIN, 0).reduced(), vector!(-1, 0));
+    /// ```
+    pub fn reduced(&self) -> Self {
+        let mut gcd = T::zero();
+        for &e in self.components.iter() {
+            let (mut a, mut b) = (gcd, e);
+            while b != T::zero() {
+                // `MIN % -1` overflows even though the remainder is zero
+                let remainder = match a.checked_div(&b) {
+                    Some(quotient) => a - quotient * b,
+                    None => T::zero(),
+                };
+                (a, b) = (b, remainder);
+            }
+            gcd = a;
+        }
+
+        if gcd == T::zero() {
+            return *self;
+        }
+
+        if gcd < T::zero() {
+            match T::zero().checked_sub(&gcd) {
+                Some(positive) => gcd = positive,
+                // Only the minimum value can't be negated, so every component is either zero or the minimum
+                None => return Self::new(self.components.map(|x| T::zero() - x / gcd)),
+            }
+        }
+
+        Self::new(self.components.map(|x| x / gcd))
     }
 }
 
-impl<T: Num + Signed + Copy, const N: usize> Vector<T, N> {
-    /// Calculates the absolute value of each component of a vector.
-    pub fn abs(&self) -> Self {
+impl<T: Num + Copy + Euclid, const N: usize> Vector<T, N> {
+    /// Calculates the component-wise Euclidean division of two vectors.
+    /// Unlike the `/` operator this rounds towards negative infinity for positive divisors, which is what you want when converting positions into chunk coordinates.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(-1, 17).div_euclid(&vector!(16, 16)), vector!(-1, 1));
+    /// ```
+    pub fn div_euclid(&self, other: &Self) -> Self {
         let mut components = [T::zero(); N];
         for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i].abs();
+            *e = self.components[i].div_euclid(&other.components[i]);
         }
         Self { components }
     }
-}
 
-impl<T: Num + Copy + Display, const N: usize> Debug for Vector<T, N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let components = self.components.map(|x| x.to_string()).join(", ");
-        f.write_fmt(format_args!("({})", components))
+    /// Calculates the component-wise Euclidean division of a vector by a scalar.
+    pub fn div_euclid_scalar(&self, other: T) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].div_euclid(&other);
+        }
+        Self { components }
+    }
+
+    /// Calculates the component-wise least nonnegative remainder of two vectors.
+    /// Unlike the `%` operator the result is never negative.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(-1, 17).rem_euclid(&vector!(16, 16)), vector!(15, 1));
+    /// ```
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].rem_euclid(&other.components[i]);
+        }
+        Self { components }
+    }
+
+    /// Calculates the component-wise least nonnegative remainder of a vector and a scalar.
+    pub fn rem_euclid_scalar(&self, other: T) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].rem_euclid(&other);
+        }
+        Self { components }
+    }
+}
+
+impl<T: Num + Copy + Euclid + PartialOrd, const N: usize> Vector<T, N> {
+    /// Wraps each component into the range `[0, size)`, treating space as a torus.
+    /// Unlike the `%` operator this handles negative components, so stepping off one edge enters from the opposite edge.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(-1, 10).wrapped(&vector!(10, 10)), vector!(9, 0));
+    /// ```
+    pub fn wrapped(&self, size: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let (x, size) = (self.components[i], size.components[i]);
+            let wrapped = x.rem_euclid(&size);
+            // For floats, tiny negative values round up to exactly `size`, which is outside the range.
+            *e = if wrapped >= size { T::zero() } else { wrapped };
+        }
+        Self { components }
+    }
+
+    /// Rounds each component to the nearest multiple of the matching component of `step`, which must be positive.
+    /// Halfway values round towards positive infinity, and negative components are handled with Euclidean division so every grid cell is the same size.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(-7, 7, 24).round_to_multiple(&vector!(16, 16, 16)), vector!(0, 0, 32));
+    /// ```
+    ///
+    /// # Panics
+    /// If any component of `step` is zero, for integer components.
+    pub fn round_to_multiple(&self, step: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let (x, step) = (self.components[i], step.components[i]);
+            let remainder = x.rem_euclid(&step);
+            let down = x - remainder;
+            *e = if remainder >= step - remainder {
+                down + step
+            } else {
+                down
+            };
+        }
+        Self { components }
+    }
+}
+
+impl<T: Num + Copy + Ord, const N: usize> Vector<T, N> {
+    /// Takes the minimum of each component of two vectors.
+    pub fn min(&self, other: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].min(other.components[i]);
+        }
+        Self { components }
+    }
+
+    /// Takes the maximum of each component of two vectors.
+    pub fn max(&self, other: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].max(other.components[i]);
+        }
+        Self { components }
+    }
+
+    /// Takes the minimum component of a vector.
+    pub fn min_component(&self) -> T {
+        self.components.iter().min().copied().unwrap()
+    }
+
+    /// Takes the maximum component of a vector.
+    pub fn max_component(&self) -> T {
+        self.components.iter().max().copied().unwrap()
+    }
+
+    /// Returns the index of the smallest component of a vector.
+    /// If multiple components are equal, the first index is returned.
+    /// Use [`Vector::fargmin`] for vectors with floating point components.
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn argmin(&self) -> usize {
+        self.arg_by(|x| x, Ordering::Less)
+    }
+
+    /// Returns the index of the largest component of a vector.
+    /// If multiple components are equal, the first index is returned.
+    /// Use [`Vector::fargmax`] for vectors with floating point components.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(3, 7, -1).argmax(), 1);
+    /// ```
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn argmax(&self) -> usize {
+        self.arg_by(|x| x, Ordering::Greater)
+    }
+
+    /// Returns a vector with the same components sorted in ascending order.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(3, 1, 2).sorted(), vector!(1, 2, 3));
+    /// ```
+    pub fn sorted(&self) -> Self {
+        let mut components = self.components;
+        components.sort_unstable();
+        Self { components }
+    }
+
+    /// Returns the indices that would sort the components in ascending order.
+    /// The sort is stable, so equal components keep their relative order.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(3, 1, 2).argsort(), [1, 2, 0]);
+    /// ```
+    pub fn argsort(&self) -> [usize; N] {
+        let mut indices = std::array::from_fn(|i| i);
+        indices.sort_by_key(|&i| self.components[i]);
+        indices
+    }
+
+    fn arg_by<K: Ord>(&self, key: impl Fn(T) -> K, ordering: Ordering) -> usize {
+        assert!(
+            N > 0,
+            "can't take the index of a component with zero dimensions"
+        );
+        let mut best = 0;
+        for i in 1..N {
+            if key(self.components[i]).cmp(&key(self.components[best])) == ordering {
+                best = i;
+            }
+        }
+        best
+    }
+}
+
+impl<T: Num + Copy + Ord + Signed, const N: usize> Vector<T, N> {
+    /// Returns the index of the component with the smallest absolute value.
+    /// If multiple components are equal, the first index is returned.
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn argmin_abs(&self) -> usize {
+        self.arg_by(|x| x.abs(), Ordering::Less)
+    }
+
+    /// Returns the index of the component with the largest absolute value.
+    /// If multiple components are equal, the first index is returned.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(3, 7, -9).argmax_abs(), 2);
+    /// ```
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn argmax_abs(&self) -> usize {
+        self.arg_by(|x| x.abs(), Ordering::Greater)
+    }
+}
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Takes the minimum component of a floating point vector.
+    /// Like [`f32::min`], NaN components are ignored, so NaN is only returned if every component is NaN.
+    /// Use this instead of [`Vector::min_component`] for vectors with floating point components.
+    pub fn fmin_component(&self) -> T {
+        self.components.iter().fold(T::nan(), |acc, &e| acc.min(e))
+    }
+
+    /// Takes the maximum component of a floating point vector.
+    /// Like [`f32::max`], NaN components are ignored, so NaN is only returned if every component is NaN.
+    /// Use this instead of [`Vector::max_component`] for vectors with floating point components.
+    pub fn fmax_component(&self) -> T {
+        self.components.iter().fold(T::nan(), |acc, &e| acc.max(e))
+    }
+
+    /// Returns the index of the smallest component of a floating point vector, ignoring NaN components.
+    /// If multiple components are equal, the first index is returned.
+    /// Returns None if every component is NaN.
+    pub fn fargmin(&self) -> Option<usize> {
+        self.farg_by(|a, b| a < b)
+    }
+
+    /// Returns the index of the largest component of a floating point vector, ignoring NaN components.
+    /// If multiple components are equal, the first index is returned.
+    /// Returns None if every component is NaN.
+    pub fn fargmax(&self) -> Option<usize> {
+        self.farg_by(|a, b| a > b)
+    }
+
+    /// Returns the index of the component with the smallest absolute value, ignoring NaN components.
+    /// If multiple components are equal, the first index is returned.
+    /// Returns None if every component is NaN.
+    pub fn fargmin_abs(&self) -> Option<usize> {
+        self.farg_by(|a, b| a.abs() < b.abs())
+    }
+
+    /// Returns the index of the component with the largest absolute value, ignoring NaN components.
+    /// If multiple components are equal, the first index is returned.
+    /// Returns None if every component is NaN.
+    pub fn fargmax_abs(&self) -> Option<usize> {
+        self.farg_by(|a, b| a.abs() > b.abs())
+    }
+
+    /// Returns a vector with the same components sorted in ascending order, with NaN components last.
+    /// Use this instead of [`Vector::sorted`] for vectors with floating point components.
+    pub fn fsorted(&self) -> Self {
+        let mut components = self.components;
+        components.sort_unstable_by(Self::fcompare);
+        Self { components }
+    }
+
+    /// Returns the indices that would sort the components in ascending order, with NaN components last.
+    /// The sort is stable, so equal components keep their relative order.
+    /// Use this instead of [`Vector::argsort`] for vectors with floating point components.
+    pub fn fargsort(&self) -> [usize; N] {
+        let mut indices: [usize; N] = std::array::from_fn(|i| i);
+        indices.sort_by(|&a, &b| Self::fcompare(&self.components[a], &self.components[b]));
+        indices
+    }
+
+    /// Returns true if every component is neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.components.iter().all(|x| x.is_finite())
+    }
+
+    /// Returns true if any component is NaN.
+    pub fn has_nan(&self) -> bool {
+        self.components.iter().any(|x| x.is_nan())
+    }
+
+    /// Returns a vector flagging which components are NaN.
+    pub fn is_nan_mask(&self) -> Vector<bool, N> {
+        Vector::new(self.components.map(|x| x.is_nan()))
+    }
+
+    fn fcompare(a: &T, b: &T) -> Ordering {
+        a.partial_cmp(b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    }
+
+    fn farg_by(&self, better: impl Fn(T, T) -> bool) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for (i, &e) in self.components.iter().enumerate() {
+            if !e.is_nan() && best.is_none_or(|b| better(e, self.components[b])) {
+                best = Some(i);
+            }
+        }
+        best
+    }
+}
+
+impl<T: Num + Copy + Signed, const N: usize> Vector<T, N> {
+    /// Calculates the opposite of a vector.
+    /// This is the vector with all components negated.
+    pub fn opposite(&self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = -self.components[i];
+        }
+        Self { components }
+    }
+
+    /// Calculates the sign of each component of a vector.
+    /// This is -1 if the component is negative, 0 if it is zero, and 1 if it is positive.
+    pub fn signum(&self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].signum();
+        }
+        Self { components }
+    }
+
+    /// Calculates the [Manhattan Distance](https://en.wikipedia.org/wiki/Taxicab_geometry#Formal_definition) of two vectors.
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        let mut out = T::zero();
+        for (&a, &b) in self.components.iter().zip(other.components.iter()) {
+            out = out + (a - b).abs();
+        }
+        out
+    }
+}
+
+impl<T: Num + Copy + Signed + PartialOrd, const N: usize> Vector<T, N> {
+    /// Returns the axis whose component has the largest absolute value.
+    /// If multiple components are equal, the first axis is returned.
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn dominant_axis(&self) -> usize {
+        assert!(N > 0, "can't find the dominant axis with zero dimensions");
+        let mut best = 0;
+        for i in 1..N {
+            if self.components[i].abs() > self.components[best].abs() {
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// Snaps a vector to the signed unit vector along its [dominant axis](Vector::dominant_axis).
+    /// The zero vector is returned unchanged.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(0.3, -0.9, 0.2).dominant_axis_vector(), vector!(0.0, -1.0, 0.0));
+    /// ```
+    ///
+    /// # Panics
+    /// If N is zero.
+    pub fn dominant_axis_vector(&self) -> Self {
+        let axis = self.dominant_axis();
+        let mut components = [T::zero(); N];
+        if !self.components[axis].is_zero() {
+            components[axis] = self.components[axis].signum();
+        }
+        Self { components }
+    }
+
+    /// Iterates over all integer points with a [Manhattan Distance](https://en.wikipedia.org/wiki/Taxicab_geometry#Formal_definition) of exactly `radius` from this vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(0, 0).manhattan_ring(2).count(), 8);
+    /// ```
+    pub fn manhattan_ring(&self, radius: T) -> impl Iterator<Item = Self> {
+        self.ring(radius, move |offset| {
+            let rest = offset
+                .components
+                .iter()
+                .fold(radius, |acc, &e| acc - e.abs());
+            (rest >= T::zero()).then_some((-rest, rest, rest + rest))
+        })
+    }
+
+    /// Iterates over all integer points with a [Chebyshev Distance](https://en.wikipedia.org/wiki/Chebyshev_distance) of exactly `radius` from this vector.
+    /// In two dimensions this is the outline of a square centered on the vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(0, 0).chebyshev_ring(2).count(), 16);
+    /// ```
+    pub fn chebyshev_ring(&self, radius: T) -> impl Iterator<Item = Self> {
+        self.ring(radius, move |offset| {
+            let on_edge = offset.components.iter().any(|e| e.abs() == radius);
+            let step = if on_edge { T::one() } else { radius + radius };
+            Some((-radius, radius, step))
+        })
+    }
+
+    /// Shared implementation of the ring iterators.
+    /// Iterates over the offsets of all but the last component in the box of the given radius, the `last` function then returns the range (start, end, step) of the last component for each offset.
+    /// A step of zero is treated as one, so the range only yields its start.
+    fn ring(
+        &self,
+        radius: T,
+        last: impl Fn(&Self) -> Option<(T, T, T)>,
+    ) -> impl Iterator<Item = Self> {
+        let center = *self;
+        let (mut min, mut max) = (Self::zero() - radius, Self::zero() + radius);
+        if N > 0 {
+            (min.components[N - 1], max.components[N - 1]) = (T::zero(), T::zero());
+        }
+
+        Self::iter_box(min, max)
+            .filter(|_| N > 0)
+            .flat_map(move |offset| {
+                let (start, end, step) = last(&offset).unwrap_or((T::one(), T::zero(), T::one()));
+                let step = if step > T::zero() { step } else { T::one() };
+                std::iter::successors(Some(start).filter(|&x| x <= end), move |&x| {
+                    Some(x + step).filter(|&x| x <= end)
+                })
+                .map(move |x| {
+                    let mut point = center + offset;
+                    point.components[N - 1] = point.components[N - 1] + x;
+                    point
+                })
+            })
+    }
+}
+
+impl<T: Num + Copy + NumCast, const N: usize> Vector<T, N> {
+    /// Calculates the centroid (mean) of a set of vectors.
+    /// Returns None if there are no vectors or the number of vectors can't be represented as `T`.
+    ///
+    /// Note that the vectors are summed before dividing, so integer components can still overflow for very large sets.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let points = [vector!(0.0, 0.0), vector!(2.0, 0.0), vector!(1.0, 3.0)];
+    /// assert_eq!(Vector::centroid(points), Some(vector!(1.0, 1.0)));
+    /// ```
+    pub fn centroid(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let (mut sum, mut count) = (Self::zero(), 0_usize);
+        for e in iter {
+            sum += e;
+            count += 1;
+        }
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(sum / T::from(count)?)
+    }
+
+    /// Calculates the mean of a set of vectors, weighting each by its paired value.
+    /// Returns None if there are no vectors or the weights sum to zero.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// // Center of mass
+    /// let bodies = [(vector!(0.0, 0.0), 3.0), (vector!(4.0, 2.0), 1.0)];
+    /// assert_eq!(Vector::weighted_mean(bodies), Some(vector!(1.0, 0.5)));
+    /// ```
+    pub fn weighted_mean(iter: impl IntoIterator<Item = (Self, T)>) -> Option<Self> {
+        let (mut sum, mut total) = (Self::zero(), T::zero());
+        for (e, weight) in iter {
+            for (x, &y) in sum.components.iter_mut().zip(e.components.iter()) {
+                *x = *x + y * weight;
+            }
+            total = total + weight;
+        }
+
+        (!total.is_zero()).then(|| sum / total)
+    }
+}
+
+impl<T: Num + Copy + Sum, const N: usize> Vector<T, N> {
+    pub fn sum(&self) -> T {
+        let mut acc = T::zero();
+        for i in self.components {
+            acc = acc + i;
+        }
+        acc
+    }
+
+    /// Calculates the sum of all squared components.
+    /// Used for calculating the magnitude of a vector.
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Calculates the dot product of two vectors.
+    pub fn dot(&self, other: &Self) -> T {
+        self.components
+            .iter()
+            .zip(other.components.iter())
+            .map(|(a, b)| *a * *b)
+            .sum()
+    }
+}
+
+impl<T: Num + Copy + Sum + Signed + PartialOrd, const N: usize> Vector<T, N> {
+    /// Orients a vector so it faces against `incident`, following the semantics of GLSL's `faceforward`.
+    /// Returns the vector unchanged if the dot product of `reference` and `incident` is negative, and its opposite otherwise.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let normal = vector!(0.0, 1.0);
+    /// assert_eq!(normal.faceforward(&vector!(0.0, -1.0), &normal), normal);
+    /// assert_eq!(normal.faceforward(&vector!(0.0, 1.0), &normal), -normal);
+    /// ```
+    pub fn faceforward(&self, incident: &Self, reference: &Self) -> Self {
+        if reference.dot(incident) < T::zero() {
+            *self
+        } else {
+            -*self
+        }
+    }
+}
+
+impl<T: Num + Copy + Sum + Real, const N: usize> Vector<T, N> {
+    /// Calculates the magnitude of a vector.
+    /// This is the square root of the sum of all squared components.
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Calculates the magnitude of a vector without overflowing or underflowing for very large or tiny components, like [`f64::hypot`].
+    /// The components are scaled by the largest absolute component before squaring, which makes this a bit slower than [`Vector::magnitude`].
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert!((vector!(3e200f64, 4e200).magnitude_stable() / 5e200 - 1.0).abs() < 1e-15);
+    /// assert_eq!(vector!(3e200, 4e200).magnitude(), f64::INFINITY);
+    /// ```
+    pub fn magnitude_stable(&self) -> T {
+        let scale = self
+            .components
+            .iter()
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        if scale == T::zero() || scale > T::max_value() {
+            return scale;
+        }
+
+        let mut sum = T::zero();
+        for &e in &self.components {
+            let scaled = e / scale;
+            sum = sum + scaled * scaled;
+        }
+        sum.sqrt() * scale
+    }
+
+    /// Normalizes a vector.
+    /// This is the vector divided by its magnitude.
+    ///
+    /// Normalizing a zero vector divides by zero, giving NaN components.
+    /// Use [`Vector::try_normalize`] if the input may be zero.
+    pub fn normalize(&self) -> Self {
+        *self / self.magnitude()
+    }
+
+    /// Normalizes a vector, returning None if its magnitude is zero.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// assert_eq!(vector!(3.0, 4.0).try_normalize(), Some(vector!(0.6, 0.8)));
+    /// assert_eq!(Vector::<f64, 2>::zero().try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Self> {
+        let magnitude = self.magnitude();
+        (magnitude != T::zero()).then(|| *self / magnitude)
+    }
+
+    /// Calculates the [Euclidean Distance](https://en.wikipedia.org/wiki/Euclidean_distance) of two vectors.
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).magnitude()
+    }
+
+    /// Calculates the [cosine similarity](https://en.wikipedia.org/wiki/Cosine_similarity) of two vectors, the cosine of the angle between them.
+    /// The result is in the range [-1, 1], and is zero if either vector is the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(3.0, 4.0).cosine_similarity(&vector!(4.0, -3.0)), 0.0);
+    /// assert_eq!(vector!(1.0, 0.0).cosine_similarity(&vector!(0.0, 0.0)), 0.0);
+    /// ```
+    pub fn cosine_similarity(&self, other: &Self) -> T {
+        let magnitudes = self.magnitude() * other.magnitude();
+        if magnitudes == T::zero() {
+            return T::zero();
+        }
+
+        (self.dot(other) / magnitudes).max(-T::one()).min(T::one())
+    }
+
+    /// Calculates the unsigned angle between two vectors, in the range [0, π].
+    /// Uses [Kahan's formula](https://people.eecs.berkeley.edu/~wkahan/Mindless.pdf), which stays accurate for nearly parallel vectors where `acos` of the cosine loses precision.
+    /// The angle to a zero vector is zero.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let angle = vector!(1.0f64, 0.0, 0.0).angle_between(&vector!(1.0, 1.0, 0.0));
+    /// assert!((angle.degrees() - 45.0).abs() < 1e-12);
+    /// ```
+    pub fn angle_between(&self, other: &Self) -> Angle<T> {
+        let (a, b) = (self.magnitude(), other.magnitude());
+        let (u, v) = (
+            Self::new(self.components.map(|x| x * b)),
+            Self::new(other.components.map(|x| x * a)),
+        );
+        let two = T::one() + T::one();
+        Angle::from_radians(two * (u - v).magnitude().atan2((u + v).magnitude()))
+    }
+
+    /// Checks if two vectors point along the same line, in either direction, by testing if the sine of the angle between them is at most `epsilon`.
+    /// The zero vector is parallel to every vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert!(vector!(1.0, 2.0).is_parallel_to(&vector!(-2.0, -4.0), 1e-9));
+    /// assert!(!vector!(1.0, 2.0).is_parallel_to(&vector!(2.0, 1.0), 1e-9));
+    /// ```
+    pub fn is_parallel_to(&self, other: &Self, epsilon: T) -> bool {
+        let magnitudes = self.magnitude_squared() * other.magnitude_squared();
+        if magnitudes == T::zero() {
+            return true;
+        }
+
+        let dot = self.dot(other);
+        let sin_squared = T::one() - dot * dot / magnitudes;
+        sin_squared <= epsilon * epsilon
+    }
+
+    /// Checks if two vectors are at right angles, by testing if the cosine of the angle between them is at most `epsilon`.
+    /// The zero vector is perpendicular to every vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert!(vector!(1.0, 2.0).is_perpendicular_to(&vector!(-4.0, 2.0), 1e-9));
+    /// ```
+    pub fn is_perpendicular_to(&self, other: &Self, epsilon: T) -> bool {
+        self.cosine_similarity(other).abs() <= epsilon
+    }
+
+    /// Orthonormalizes a set of vectors with the [modified Gram-Schmidt process](https://en.wikipedia.org/wiki/Gram%E2%80%93Schmidt_process).
+    /// Vectors that are linearly dependent on the previous ones, leaving a remainder with a magnitude of at most `epsilon`, are skipped.
+    /// The length of the result is therefore the rank of the input, which is never more than N.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let basis = Vector::gram_schmidt(&[vector!(2.0, 0.0), vector!(4.0, 0.0), vector!(1.0, 1.0)], 1e-9);
+    /// assert_eq!(basis, [vector!(1.0, 0.0), vector!(0.0, 1.0)]);
+    /// ```
+    pub fn gram_schmidt(vectors: &[Self], epsilon: T) -> Vec<Self> {
+        let mut basis: Vec<Self> = Vec::with_capacity(N);
+        for &vector in vectors {
+            if basis.len() == N {
+                break;
+            }
+
+            let mut remainder = vector;
+            for axis in &basis {
+                let scale = remainder.dot(axis);
+                for (e, &a) in remainder.components.iter_mut().zip(axis.components.iter()) {
+                    *e = *e - a * scale;
+                }
+            }
+
+            let magnitude = remainder.magnitude();
+            if magnitude > epsilon {
+                basis.push(remainder / magnitude);
+            }
+        }
+        basis
+    }
+
+    /// Projects a vector onto the plane through the origin with the given normal, removing the component along the normal.
+    /// The normal doesn't need to be normalized, but must not be the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // Slide along a wall facing +x
+    /// let velocity = vector!(-3.0, 1.0, 2.0);
+    /// assert_eq!(velocity.project_onto_plane(&vector!(2.0, 0.0, 0.0)), vector!(0.0, 1.0, 2.0));
+    /// ```
+    pub fn project_onto_plane(&self, normal: &Self) -> Self {
+        let scale = self.dot(normal) / normal.dot(normal);
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i] - normal.components[i] * scale;
+        }
+        Self { components }
+    }
+
+    /// Reflects a point across the plane with the normal `plane_normal` through `plane_point`, giving its mirror image on the other side.
+    /// The plane doesn't need to pass through the origin, unlike a reflection matrix.
+    /// The normal doesn't need to be normalized, but must not be the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let mirrored = vector!(1.0, 5.0, 2.0).reflect_across_plane(&vector!(0.0, 2.0, 0.0), &vector!(0.0, 3.0, 0.0));
+    /// assert_eq!(mirrored, vector!(1.0, 1.0, 2.0));
+    /// ```
+    pub fn reflect_across_plane(&self, plane_normal: &Self, plane_point: &Self) -> Self {
+        let distance = (*self - *plane_point).dot(plane_normal) / plane_normal.dot(plane_normal);
+        let scale = distance + distance;
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i] - plane_normal.components[i] * scale;
+        }
+        Self { components }
+    }
+
+    /// Checks if every component of two vectors differs by at most `epsilon`.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert!(vector!(0.1 + 0.2, 1.0).approx_eq(&vector!(0.3, 1.0), 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.components
+            .iter()
+            .zip(other.components.iter())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if every component of two vectors differs by at most `epsilon` times the larger of their absolute values.
+    /// This scales the tolerance with the magnitude of the components, which makes it suitable for both very large and very small values.
+    pub fn approx_eq_relative(&self, other: &Self, epsilon: T) -> bool {
+        self.components
+            .iter()
+            .zip(other.components.iter())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon * a.abs().max(b.abs()))
+    }
+}
+
+impl<T: Real, const N: usize> Vector<T, N> {
+    /// Linearly interpolates between two vectors.
+    /// A `t` of zero gives this vector and a `t` of one gives `other`, but values outside of that range are allowed.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        self.lerp_per_component(other, &Self::new([t; N]))
+    }
+
+    /// Moves towards `target` with exponential damping, for smoothing like camera follow that behaves the same at any frame rate.
+    /// `lambda` sets how quickly the target is approached, and `dt` is the time since the last update.
+    /// This is `lerp(target, 1 - e^(-lambda * dt))`, so one step of `2 * dt` lands exactly where two steps of `dt` would.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let (start, target) = (vector!(0.0, 0.0), vector!(10.0, 0.0));
+    /// let once = start.exp_decay(&target, 2.0, 0.5);
+    /// let twice = start.exp_decay(&target, 2.0, 0.25).exp_decay(&target, 2.0, 0.25);
+    /// assert!(once.approx_eq(&twice, 1e-12));
+    /// ```
+    pub fn exp_decay(&self, target: &Self, lambda: T, dt: T) -> Self {
+        self.lerp(target, T::one() - (-lambda * dt).exp())
+    }
+
+    /// Linearly interpolates between two vectors with a separate interpolation factor for each axis.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let blended = vector!(0.0, 0.0).lerp_per_component(&vector!(10.0, 10.0), &vector!(0.25, 1.0));
+    /// assert_eq!(blended, vector!(2.5, 10.0));
+    /// ```
+    pub fn lerp_per_component(&self, other: &Self, t: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let (a, b) = (self.components[i], other.components[i]);
+            *e = a + (b - a) * t.components[i];
+        }
+        Self { components }
+    }
+
+    /// Finds the interpolation factor of `value` between `a` and `b` along each axis, the inverse of [`Vector::lerp`].
+    /// Axes where `a` and `b` are equal divide by zero.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let t = Vector::inverse_lerp(&vector!(0.0, 10.0), &vector!(4.0, 20.0), &vector!(1.0, 25.0));
+    /// assert_eq!(t, vector!(0.25, 1.5));
+    /// ```
+    pub fn inverse_lerp(a: &Self, b: &Self, value: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let (a, b) = (a.components[i], b.components[i]);
+            *e = (value.components[i] - a) / (b - a);
+        }
+        Self { components }
+    }
+
+    /// Maps each component from the range between `in_min` and `in_max` to the matching position between `out_min` and `out_max`.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // World coordinates to UVs
+    /// let uv = vector!(150.0, 25.0).remap(&vector!(100.0, 0.0), &vector!(200.0, 100.0), &vector!(0.0, 0.0), &vector!(1.0, 1.0));
+    /// assert_eq!(uv, vector!(0.5, 0.25));
+    /// ```
+    pub fn remap(&self, in_min: &Self, in_max: &Self, out_min: &Self, out_max: &Self) -> Self {
+        out_min.lerp_per_component(out_max, &Self::inverse_lerp(in_min, in_max, self))
+    }
+
+    /// Rounds each component to the nearest multiple of `cell_size`.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1.2, -3.9).snap_to_grid(0.5), vector!(1.0, -4.0));
+    /// ```
+    pub fn snap_to_grid(&self, cell_size: T) -> Self {
+        self.snap_to_grid_per_axis(&Self::new([cell_size; N]))
+    }
+
+    /// Rounds each component to the nearest multiple of the matching component of `cell_size`.
+    pub fn snap_to_grid_per_axis(&self, cell_size: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let size = cell_size.components[i];
+            *e = (self.components[i] / size).round() * size;
+        }
+        Self { components }
+    }
+
+    /// Rounds each component down to a multiple of `cell_size`, giving the corner of the grid cell containing the vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1.2, -3.9).floor_to_grid(0.5), vector!(1.0, -4.0));
+    /// ```
+    pub fn floor_to_grid(&self, cell_size: T) -> Self {
+        self.floor_to_grid_per_axis(&Self::new([cell_size; N]))
+    }
+
+    /// Rounds each component down to a multiple of the matching component of `cell_size`.
+    pub fn floor_to_grid_per_axis(&self, cell_size: &Self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            let size = cell_size.components[i];
+            *e = (self.components[i] / size).floor() * size;
+        }
+        Self { components }
+    }
+
+    /// Divides each component by the sum of all components, so the result sums to one.
+    /// This turns a vector of non-negative weights into a probability distribution.
+    /// Returns None if the components sum to zero.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1.0, 3.0).normalized_sum(), Some(vector!(0.25, 0.75)));
+    /// ```
+    pub fn normalized_sum(&self) -> Option<Self> {
+        let sum = self.components.iter().fold(T::zero(), |acc, &x| acc + x);
+        (sum != T::zero()).then(|| Self::new(self.components.map(|x| x / sum)))
+    }
+
+    /// Calculates the [softmax](https://en.wikipedia.org/wiki/Softmax_function) of the vector, a probability distribution where larger components get exponentially more weight.
+    /// The largest component is subtracted before exponentiating, so large inputs don't overflow.
+    /// If the largest component is infinite, the weight is split evenly between the components equal to it, so a vector of only negative infinities gives a uniform distribution.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let probabilities = vector!(1000.0, 1000.0, f64::NEG_INFINITY).softmax();
+    /// assert_eq!(probabilities, vector!(0.5, 0.5, 0.0));
+    /// ```
+    pub fn softmax(&self) -> Self {
+        let max = match self.components.iter().copied().reduce(T::max) {
+            Some(max) => max,
+            None => return *self,
+        };
+        if max < T::min_value() || max > T::max_value() {
+            let count = self.components.iter().filter(|&&x| x == max).count();
+            let weight = T::one() / T::from(count).unwrap();
+            return Self::new(
+                self.components
+                    .map(|x| if x == max { weight } else { T::zero() }),
+            );
+        }
+
+        let exp = self.components.map(|x| (x - max).exp());
+        let sum = exp.iter().fold(T::zero(), |acc, &x| acc + x);
+        Self::new(exp.map(|x| x / sum))
+    }
+
+    /// Calculates the population variance along each axis of a set of vectors.
+    /// Uses [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm), so the vectors are only iterated once.
+    /// Returns None if there are no vectors.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let points = [vector!(1.0, 5.0), vector!(3.0, 5.0)];
+    /// assert_eq!(Vector::variance(points), Some(vector!(1.0, 0.0)));
+    /// ```
+    pub fn variance(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let (mut mean, mut m2) = ([T::zero(); N], [T::zero(); N]);
+        let mut count = T::zero();
+
+        for e in iter {
+            count = count + T::one();
+            for (i, &x) in e.components.iter().enumerate() {
+                let delta = x - mean[i];
+                mean[i] = mean[i] + delta / count;
+                m2[i] = m2[i] + delta * (x - mean[i]);
+            }
+        }
+
+        (count > T::zero()).then(|| Self::new(m2.map(|x| x / count)))
+    }
+
+    /// Calculates the population standard deviation along each axis of a set of vectors.
+    /// This is the square root of [`Vector::variance`].
+    /// Returns None if there are no vectors.
+    pub fn std_dev(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+        Self::variance(iter).map(|x| Self::new(x.components.map(|x| x.sqrt())))
+    }
+
+    /// Calculates the population covariance matrix of a set of vectors.
+    /// Element `(i, j)` is the covariance between axes `i` and `j`, so the diagonal holds the [`Vector::variance`] of each axis.
+    /// The vectors are only iterated once.
+    /// Returns None if there are no vectors.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Matrix, Vector, vector};
+    /// let points = [vector!(0.0, 0.0), vector!(2.0, 2.0)];
+    /// assert_eq!(Vector::covariance(points), Some(Matrix::new([[1.0, 1.0], [1.0, 1.0]])));
+    /// ```
+    pub fn covariance(iter: impl IntoIterator<Item = Self>) -> Option<Matrix<T, N, N>> {
+        let (mut mean, mut comoment) = ([T::zero(); N], [[T::zero(); N]; N]);
+        let mut count = T::zero();
+
+        for e in iter {
+            count = count + T::one();
+            let delta = std::array::from_fn::<_, N, _>(|i| e.components[i] - mean[i]);
+            for (i, x) in mean.iter_mut().enumerate() {
+                *x = *x + delta[i] / count;
+            }
+            for (i, row) in comoment.iter_mut().enumerate() {
+                for (j, x) in row.iter_mut().enumerate() {
+                    *x = *x + delta[i] * (e.components[j] - mean[j]);
+                }
+            }
+        }
+
+        (count > T::zero()).then(|| Matrix::new(comoment.map(|row| row.map(|x| x / count))))
+    }
+
+    /// Performs a [principal component analysis](https://en.wikipedia.org/wiki/Principal_component_analysis) of a set of vectors.
+    /// Returns the principal axes as unit vectors along with the variance of the vectors along each axis, ordered from the largest variance to the smallest.
+    /// The axes are the eigenvectors of the [`Vector::covariance`] matrix, so they are orthogonal to each other, but the sign of each is arbitrary.
+    /// Returns None if there are no vectors.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Vector, vector};
+    /// let points = [vector!(-2.0, -2.0), vector!(-1.0, -1.0), vector!(1.0, 1.0), vector!(2.0, 2.0)];
+    /// let ([major, _], variances) = Vector::principal_axes(points).unwrap();
+    ///
+    /// let diagonal = vector!(1.0, 1.0).normalize();
+    /// assert!(major.approx_eq(&diagonal, 1e-12) || major.approx_eq(&-diagonal, 1e-12));
+    /// assert!(variances.approx_eq(&vector!(5.0, 0.0), 1e-12));
+    /// ```
+    pub fn principal_axes(iter: impl IntoIterator<Item = Self>) -> Option<([Self; N], Self)> {
+        let (variances, vectors) = Self::covariance(iter)?.symmetric_eigen();
+
+        let mut order = std::array::from_fn::<_, N, _>(|i| i);
+        order.sort_by(|&a, &b| {
+            let (a, b) = (variances.components[a], variances.components[b]);
+            b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+        });
+
+        Some((
+            order.map(|i| vectors.column(i)),
+            Self::new(order.map(|i| variances.components[i])),
+        ))
+    }
+}
+
+impl<T: Num + Signed + Copy, const N: usize> Vector<T, N> {
+    /// Calculates the absolute value of each component of a vector.
+    pub fn abs(&self) -> Self {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].abs();
+        }
+        Self { components }
+    }
+}
+
+impl<T: Display, const N: usize> Vector<T, N> {
+    /// Writes the vector as a LaTeX column vector, using the `pmatrix` environment from amsmath.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1, -2, 3).to_latex(), r"\begin{pmatrix} 1 \\ -2 \\ 3 \end{pmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let components = self
+            .components
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(r" \\ ");
+        format!(r"\begin{{pmatrix}} {components} \end{{pmatrix}}")
+    }
+
+    /// Writes the vector as a column, one component per line between brackets.
+    /// Components are aligned on their decimal points, which makes columns of numbers easy to compare.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1.5, -20.0, 3.25).to_column_string(), "[   1.5  ]\n[ -20    ]\n[   3.25 ]");
+    /// ```
+    pub fn to_column_string(&self) -> String {
+        let components = self
+            .components
+            .iter()
+            .map(|x| {
+                let x = x.to_string();
+                let point = x.find('.').unwrap_or(x.len());
+                let (integer, fraction) = x.split_at(point);
+                (integer.to_owned(), fraction.to_owned())
+            })
+            .collect::<Vec<_>>();
+        let integer_width = components
+            .iter()
+            .map(|x| x.0.chars().count())
+            .max()
+            .unwrap_or(0);
+        let fraction_width = components
+            .iter()
+            .map(|x| x.1.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        components
+            .iter()
+            .map(|(integer, fraction)| {
+                format!("[ {integer:>integer_width$}{fraction:<fraction_width$} ]")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Display, const N: usize> Debug for Vector<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let components = self
+            .components
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        f.write_fmt(format_args!("({})", components))
     }
 }
 
-impl<T: Num + Copy, const N: usize> Copy for Vector<T, N> {}
+/// Formats each component with the given trait, forwarding flags like precision and `#` to every component.
+macro_rules! format_op {
+    ($trait:tt) => {
+        impl<T: $trait, const N: usize> $trait for Vector<T, N> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("(")?;
+                for (i, e) in self.components.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    $trait::fmt(e, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+format_op!(LowerExp);
+format_op!(UpperExp);
+format_op!(Binary);
+format_op!(Octal);
+format_op!(LowerHex);
+format_op!(UpperHex);
+
+impl<T: Copy, const N: usize> Copy for Vector<T, N> {}
 impl<T: Num + Copy, const N: usize> Eq for Vector<T, N> {}
 
 impl<T: Num + Copy, const N: usize> FromIterator<T> for Vector<T, N> {
@@ -279,11 +1803,9 @@ macro_rules! bin_op {
         impl<T: Num + Copy, const N: usize> $trait for Vector<T, N> {
             type Output = Self;
 
+            #[track_caller]
             fn $func(self, other: Self) -> Self::Output {
-                let mut components = [T::zero(); N];
-                for (i, e) in components.iter_mut().enumerate() {
-                    *e = self.components[i].$func(other.components[i]);
-                }
+                let components = zip_components!(&self.components, &other.components, $func);
                 Self { components }
             }
         }
@@ -291,11 +1813,9 @@ macro_rules! bin_op {
         impl<T: Num + Copy, const N: usize> $trait<T> for Vector<T, N> {
             type Output = Self;
 
+            #[track_caller]
             fn $func(self, other: T) -> Self::Output {
-                let mut components = [T::zero(); N];
-                for (i, e) in components.iter_mut().enumerate() {
-                    *e = self.components[i].$func(other);
-                }
+                let components = zip_components!(&self.components, &[other; N], $func);
                 Self { components }
             }
         }
@@ -310,10 +1830,9 @@ bin_op!(Rem, rem);
 macro_rules! assign_op {
     ($trait:tt, $func:ident, $op:ident) => {
         impl<T: Num + Copy, const N: usize> $trait for Vector<T, N> {
+            #[track_caller]
             fn $func(&mut self, rhs: Self) {
-                for (i, e) in self.components.iter_mut().enumerate() {
-                    *e = e.$op(rhs.components[i]);
-                }
+                self.components = zip_components!(&self.components, &rhs.components, $op);
             }
         }
     };
@@ -324,20 +1843,295 @@ assign_op!(SubAssign, sub_assign, sub);
 assign_op!(DivAssign, div_assign, div);
 assign_op!(RemAssign, rem_assign, rem);
 
-impl<T: Num + Copy, const N: usize> Neg for Vector<T, N> {
+macro_rules! bit_op {
+    ($trait:tt, $func:ident, $assign_trait:tt, $assign_func:ident) => {
+        impl<T: Copy + $trait<Output = T>, const N: usize> $trait for Vector<T, N> {
+            type Output = Self;
+
+            fn $func(self, other: Self) -> Self::Output {
+                let mut components = self.components;
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = e.$func(other.components[i]);
+                }
+                Self { components }
+            }
+        }
+
+        impl<T: Copy + $trait<Output = T>, const N: usize> $trait<T> for Vector<T, N> {
+            type Output = Self;
+
+            fn $func(self, other: T) -> Self::Output {
+                Self::new(self.components.map(|e| e.$func(other)))
+            }
+        }
+
+        impl<T: Copy + $trait<Output = T>, const N: usize> $assign_trait for Vector<T, N> {
+            fn $assign_func(&mut self, rhs: Self) {
+                *self = self.$func(rhs);
+            }
+        }
+
+        impl<T: Copy + $trait<Output = T>, const N: usize> $assign_trait<T> for Vector<T, N> {
+            fn $assign_func(&mut self, rhs: T) {
+                *self = self.$func(rhs);
+            }
+        }
+    };
+}
+
+bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+macro_rules! shift_op {
+    ($trait:tt, $func:ident, $assign_trait:tt, $assign_func:ident) => {
+        impl<T: Copy + $trait<u32, Output = T>, const N: usize> $trait<u32> for Vector<T, N> {
+            type Output = Self;
+
+            #[track_caller]
+            fn $func(self, other: u32) -> Self::Output {
+                let mut components = self.components;
+                for e in components.iter_mut() {
+                    *e = e.$func(other);
+                }
+                Self { components }
+            }
+        }
+
+        impl<T: Copy + $trait<u32, Output = T>, const N: usize> $assign_trait<u32>
+            for Vector<T, N>
+        {
+            #[track_caller]
+            fn $assign_func(&mut self, rhs: u32) {
+                *self = self.$func(rhs);
+            }
+        }
+    };
+}
+
+shift_op!(Shl, shl, ShlAssign, shl_assign);
+shift_op!(Shr, shr, ShrAssign, shr_assign);
+
+impl<T: Copy + Not<Output = T>, const N: usize> Not for Vector<T, N> {
+    type Output = Self;
+
+    /// Inverts all bits of each component, or negates each component of a boolean vector.
+    fn not(self) -> Self::Output {
+        Self::new(self.components.map(|e| !e))
+    }
+}
+
+macro_rules! wrapping_op {
+    ($trait:tt, $func:ident, $scalar_func:ident, $name:literal) => {
+        impl<T: Num + Copy + $trait, const N: usize> Vector<T, N> {
+            #[doc = concat!("Component-wise wrapping ", $name, " of two vectors.")]
+            /// Overflowing components wrap around at the boundary of the type.
+            pub fn $func(&self, other: &Self) -> Self {
+                let mut components = [T::zero(); N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = self.components[i].$func(&other.components[i]);
+                }
+                Self { components }
+            }
+
+            #[doc = concat!("Wrapping ", $name, " of each component and a scalar.")]
+            /// Overflowing components wrap around at the boundary of the type.
+            pub fn $scalar_func(&self, other: T) -> Self {
+                let mut components = [T::zero(); N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = self.components[i].$func(&other);
+                }
+                Self { components }
+            }
+        }
+    };
+}
+
+wrapping_op!(WrappingAdd, wrapping_add, wrapping_add_scalar, "addition");
+wrapping_op!(
+    WrappingSub,
+    wrapping_sub,
+    wrapping_sub_scalar,
+    "subtraction"
+);
+wrapping_op!(
+    WrappingMul,
+    wrapping_mul,
+    wrapping_mul_scalar,
+    "multiplication"
+);
+
+macro_rules! checked_op {
+    ($trait:tt, $func:ident, $name:literal) => {
+        impl<T: Num + Copy + $trait, const N: usize> Vector<T, N> {
+            #[doc = concat!("Component-wise checked ", $name, " of two vectors.")]
+            /// Returns None if the operation overflows or divides by zero for any component.
+            pub fn $func(&self, other: &Self) -> Option<Self> {
+                let mut components = [T::zero(); N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = self.components[i].$func(&other.components[i])?;
+                }
+                Some(Self { components })
+            }
+        }
+    };
+}
+
+checked_op!(CheckedAdd, checked_add, "addition");
+checked_op!(CheckedSub, checked_sub, "subtraction");
+checked_op!(CheckedMul, checked_mul, "multiplication");
+checked_op!(CheckedDiv, checked_div, "division");
+
+impl<T: Num + Copy + MulAdd<Output = T>, const N: usize> Vector<T, N> {
+    /// Computes `self * a + b` for each component with [`MulAdd`], which rounds only once for floats.
+    /// This is both more precise and more convenient for integration steps like `position + velocity * dt`.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let (position, velocity) = (vector!(1.0, 2.0), vector!(4.0, -2.0));
+    /// assert_eq!(velocity.mul_add(0.5, &position), vector!(3.0, 1.0));
+    /// ```
+    pub fn mul_add(&self, a: T, b: &Self) -> Self {
+        let mut components = self.components;
+        for (e, &b) in components.iter_mut().zip(b.components.iter()) {
+            *e = e.mul_add(a, b);
+        }
+        Self { components }
+    }
+}
+
+impl<T: Num + Copy + CheckedDiv, const N: usize> Vector<T, N> {
+    /// Divides each component by a scalar.
+    /// Returns None if the divisor is zero or the division overflows for any component.
+    pub fn checked_div_scalar(&self, other: T) -> Option<Self> {
+        self.checked_div(&Self::new([other; N]))
+    }
+}
+
+macro_rules! widening_op {
+    ($func:ident, $op:ident, $name:literal) => {
+        impl<T: Num + Copy + NumCast, const N: usize> Vector<T, N> {
+            #[doc = concat!("Component-wise ", $name, " of a vector with a narrower component type, which is cast to the type of this vector first.")]
+            /// Returns None if any component of `other` can't be represented in this type.
+            #[track_caller]
+            pub fn $func<K: Copy + ToPrimitive>(&self, other: &Vector<K, N>) -> Option<Self> {
+                let other = other.num_cast::<T>()?;
+                let components = zip_components!(&self.components, &other.components, $op);
+                Some(Self { components })
+            }
+        }
+    };
+}
+
+widening_op!(widening_add, add, "addition");
+widening_op!(widening_sub, sub, "subtraction");
+widening_op!(widening_mul, mul, "multiplication");
+
+macro_rules! overflowing_op {
+    ($trait:tt, $func:ident, $name:literal) => {
+        impl<T: Num + Copy + $trait, const N: usize> Vector<T, N> {
+            #[doc = concat!("Component-wise overflowing ", $name, " of two vectors.")]
+            /// Returns the wrapped result along with a vector flagging which components overflowed.
+            pub fn $func(&self, other: &Self) -> (Self, Vector<bool, N>) {
+                let mut components = [T::zero(); N];
+                let mut overflowed = [false; N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    (*e, overflowed[i]) = self.components[i].$func(&other.components[i]);
+                }
+                (Self { components }, Vector::new(overflowed))
+            }
+        }
+    };
+}
+
+overflowing_op!(OverflowingAdd, overflowing_add, "addition");
+overflowing_op!(OverflowingSub, overflowing_sub, "subtraction");
+overflowing_op!(OverflowingMul, overflowing_mul, "multiplication");
+
+macro_rules! saturating_op {
+    ($trait:tt, $func:ident, $scalar_func:ident, $name:literal) => {
+        impl<T: Num + Copy + $trait, const N: usize> Vector<T, N> {
+            #[doc = concat!("Component-wise saturating ", $name, " of two vectors.")]
+            /// Overflowing components are clamped to the bounds of the type.
+            pub fn $func(&self, other: &Self) -> Self {
+                let mut components = [T::zero(); N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = self.components[i].$func(&other.components[i]);
+                }
+                Self { components }
+            }
+
+            #[doc = concat!("Saturating ", $name, " of each component and a scalar.")]
+            /// Overflowing components are clamped to the bounds of the type.
+            pub fn $scalar_func(&self, other: T) -> Self {
+                let mut components = [T::zero(); N];
+                for (i, e) in components.iter_mut().enumerate() {
+                    *e = self.components[i].$func(&other);
+                }
+                Self { components }
+            }
+        }
+    };
+}
+
+saturating_op!(
+    SaturatingAdd,
+    saturating_add,
+    saturating_add_scalar,
+    "addition"
+);
+saturating_op!(
+    SaturatingSub,
+    saturating_sub,
+    saturating_sub_scalar,
+    "subtraction"
+);
+saturating_op!(
+    SaturatingMul,
+    saturating_mul,
+    saturating_mul_scalar,
+    "multiplication"
+);
+
+impl<T: Num + Copy + Signed, const N: usize> Neg for Vector<T, N> {
     type Output = Self;
 
     /// Negates all components of a vector.
+    /// Only signed types can be negated, use [`Vector::checked_neg`] to negate unsigned vectors.
+    /// ```compile_fail
+    /// # use nd_vec::vector;
+    /// let v = -vector!(1u32, 2);
+    /// ```
+    #[track_caller]
     fn neg(self) -> Self::Output {
         let mut components = [T::zero(); N];
         for (i, e) in components.iter_mut().enumerate() {
-            *e = T::zero() - self.components[i];
+            *e = -self.components[i];
         }
         Self { components }
     }
 }
 
-impl<T: Num + Copy, const N: usize> PartialEq for Vector<T, N> {
+impl<T: Num + Copy + CheckedNeg, const N: usize> Vector<T, N> {
+    /// Negates all components of a vector, returning None if any component overflows.
+    /// For unsigned types this only succeeds for the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1i8, -2).checked_neg(), Some(vector!(-1, 2)));
+    /// assert_eq!(vector!(i8::MIN, 0).checked_neg(), None);
+    /// assert_eq!(vector!(1u8, 0).checked_neg(), None);
+    /// ```
+    pub fn checked_neg(&self) -> Option<Self> {
+        let mut components = [T::zero(); N];
+        for (i, e) in components.iter_mut().enumerate() {
+            *e = self.components[i].checked_neg()?;
+        }
+        Some(Self { components })
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Vector<T, N> {
     fn eq(&self, other: &Self) -> bool {
         self.components
             .iter()
@@ -349,11 +2143,9 @@ impl<T: Num + Copy, const N: usize> PartialEq for Vector<T, N> {
 impl<T: Num + Copy + Send + Sync, const N: usize> Mul<T> for Vector<T, N> {
     type Output = Self;
 
+    #[track_caller]
     fn mul(self, rhs: T) -> Self::Output {
-        let mut components = [T::zero(); N];
-        for (i, e) in components.iter_mut().enumerate() {
-            *e = self.components[i] * rhs;
-        }
+        let components = zip_components!(&self.components, &[rhs; N], mul);
         Self { components }
     }
 }
@@ -391,4 +2183,120 @@ impl<T: Copy> Vector<T, 3> {
     pub fn z(&self) -> T {
         self.components[2]
     }
+
+    /// Drops the z component, projecting onto the XY plane.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// assert_eq!(vector!(1, 2, 3).xy(), vector!(1, 2));
+    /// ```
+    pub fn xy(&self) -> Vector<T, 2> {
+        Vector::new([self.x(), self.y()])
+    }
+
+    /// Drops the y component, projecting onto the XZ plane.
+    pub fn xz(&self) -> Vector<T, 2> {
+        Vector::new([self.x(), self.z()])
+    }
+
+    /// Drops the x component, projecting onto the YZ plane.
+    pub fn yz(&self) -> Vector<T, 2> {
+        Vector::new([self.y(), self.z()])
+    }
+}
+
+impl<T: Copy> Vector<T, 4> {
+    /// Drops the last component, such as the w component of homogeneous coordinates.
+    pub fn xyz(&self) -> Vector<T, 3> {
+        let [x, y, z, _] = self.components;
+        Vector::new([x, y, z])
+    }
+}
+
+impl<T: Real> Vector<T, 2> {
+    /// Calculates the angle to rotate this vector onto `other`, in the range (-π, π].
+    /// Positive angles are counterclockwise, so the sign tells which way to turn.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Angle, vector};
+    /// assert_eq!(vector!(1.0, 0.0).signed_angle_to(&vector!(0.0, 1.0)), Angle::from_degrees(90.0));
+    /// assert_eq!(vector!(1.0, 0.0).signed_angle_to(&vector!(0.0, -1.0)), Angle::from_degrees(-90.0));
+    /// ```
+    pub fn signed_angle_to(&self, other: &Self) -> Angle<T> {
+        let [ax, ay] = self.components;
+        let [bx, by] = other.components;
+        let angle = (ax * by - ay * bx).atan2(ax * bx + ay * by);
+
+        // `atan2` gives -π for a negative zero perp-dot product, which is the same turn as π.
+        let pi = T::zero().atan2(-T::one());
+        Angle::from_radians(if angle == -pi { pi } else { angle })
+    }
+
+    /// Rotates the vector counterclockwise by the given angle.
+    ///
+    /// ```rust
+    /// # use nd_vec::{Angle, vector};
+    /// let rotated = vector!(2.0, 0.0).rotate(Angle::from_degrees(90.0));
+    /// assert!(rotated.approx_eq(&vector!(0.0, 2.0), 1e-12));
+    /// ```
+    pub fn rotate(&self, angle: Angle<T>) -> Self {
+        Rotation2::from_angle(angle).apply(self)
+    }
+
+    /// Reflects a point across the line in the direction `direction` through `line_point`, giving its mirror image on the other side.
+    /// This is the 2D counterpart of [`Vector::reflect_across_plane`], taking the direction of the line in place of the plane normal, followed by a point on the line.
+    /// The direction doesn't need to be normalized, but must not be the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// // Mirror across the diagonal line y = x + 1
+    /// let mirrored = vector!(3.0, 0.0).reflect_across_line(&vector!(1.0, 1.0), &vector!(0.0, 1.0));
+    /// assert_eq!(mirrored, vector!(-1.0, 4.0));
+    /// ```
+    pub fn reflect_across_line(&self, direction: &Self, line_point: &Self) -> Self {
+        let [dx, dy] = direction.components;
+        let [ox, oy] = (*self - *line_point).components;
+        let scale = (ox * dx + oy * dy) / (dx * dx + dy * dy);
+        let two = T::one() + T::one();
+        let [px, py] = line_point.components;
+        Self::new([px + two * dx * scale - ox, py + two * dy * scale - oy])
+    }
+}
+
+impl<T: Float> Vector<T, 3> {
+    /// Builds two unit vectors that form an orthonormal basis together with this vector, such as the tangent and bitangent of a normal.
+    /// Uses the branchless method from [Duff et al.](https://graphics.pixar.com/library/OrthonormalB/paper.pdf), which is stable for every direction.
+    /// The vector is normalized first, so it must not be the zero vector.
+    ///
+    /// ```rust
+    /// # use nd_vec::vector;
+    /// let normal = vector!(0.0, 0.0, 1.0);
+    /// let (tangent, bitangent) = normal.orthonormal_basis();
+    /// assert_eq!((tangent, bitangent), (vector!(1.0, 0.0, 0.0), vector!(0.0, 1.0, 0.0)));
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Self, Self) {
+        let length = (self.x() * self.x() + self.y() * self.y() + self.z() * self.z()).sqrt();
+        let [x, y, z] = self.components.map(|e| e / length);
+
+        let sign = T::one().copysign(z);
+        let a = -T::one() / (sign + z);
+        let b = x * y * a;
+        (
+            Self::new([T::one() + sign * x * x * a, sign * b, -sign * x]),
+            Self::new([b, sign + y * y * a, -y]),
+        )
+    }
+}
+
+impl<const N: usize> Vector<bool, N> {
+    /// Picks each component from `if_true` where the mask is true and from `if_false` where it is false.
+    pub fn select<T: Copy>(&self, if_true: &Vector<T, N>, if_false: &Vector<T, N>) -> Vector<T, N> {
+        Vector::new(std::array::from_fn(|i| {
+            if self.components[i] {
+                if_true.components[i]
+            } else {
+                if_false.components[i]
+            }
+        }))
+    }
 }